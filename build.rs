@@ -6,11 +6,14 @@ fn main() {
 #[cfg(feature = "build_bindings")]
 fn main() {
     println!("cargo:rerun-if-changed=build.rs"); // avoids double-build when we output into src
+    println!("cargo:rerun-if-changed=src/format_info.txt");
+    println!("cargo:rerun-if-changed=src/ffmpeg_pix_fmts.txt");
     generate::generate().unwrap();
 }
 
 #[cfg(feature = "build_bindings")]
 mod generate {
+    use std::collections::{HashMap, HashSet};
     use std::error::Error;
     use std::io::Write;
     use std::process::{Command, Stdio};
@@ -21,6 +24,8 @@ mod generate {
     use std::path::{Path, PathBuf};
 
     const CONST_PREFIX: &str = "DRM_FOURCC_";
+    const FORMAT_INFO_PATH: &str = "src/format_info.txt";
+    const FFMPEG_PIX_FMTS_PATH: &str = "src/ffmpeg_pix_fmts.txt";
 
     pub fn get_header_include_paths() -> Vec<PathBuf> {
         let library = pkg_config::Config::new()
@@ -200,7 +205,7 @@ mod generate {
             writeln!(as_enum, "impl {} {{", name)?;
             writeln!(
                 as_enum,
-                "pub(crate) fn from_{}(n: {}) -> Option<Self> {{\n",
+                "pub(crate) const fn from_{}(n: {}) -> Option<Self> {{\n",
                 repr, repr
             )?;
             as_enum.write_all(b"match n {\n")?;
@@ -210,19 +215,186 @@ mod generate {
             }
 
             writeln!(as_enum, "_ => None")?;
+            as_enum.write_all(b"}}\n")?;
+
+            let named: Vec<(String, &str, &str)> = names
+                .iter()
+                .map(|(full, short)| (enum_member_case(short), *full, *short))
+                .collect();
+            write_names(as_enum, &named, false)?;
+
+            as_enum.write_all(b"}\n")?;
+
+            Ok(())
+        }
+
+        // Plane and block layout of every format, from the hand-maintained table
+        fn write_format_info(
+            as_enum: &mut File,
+            names: &[(&str, &str)],
+        ) -> Result<(), Box<dyn Error + Sync + Send>> {
+            let table = std::fs::read_to_string(FORMAT_INFO_PATH)?;
+            let rows: HashMap<&str, Vec<&str>> = table
+                .lines()
+                .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+                .map(|line| {
+                    let mut fields = line.split_whitespace();
+                    (fields.next().unwrap(), fields.collect())
+                })
+                .collect();
+
+            let missing: Vec<&str> = names
+                .iter()
+                .map(|(_, short)| *short)
+                .filter(|short| !rows.contains_key(short))
+                .collect();
+            if !missing.is_empty() {
+                return Err(format!(
+                    "{} has no layout for {}",
+                    FORMAT_INFO_PATH,
+                    missing.join(", ")
+                )
+                .into());
+            }
+
+            fn planes(column: &str) -> String {
+                let mut values: Vec<&str> = column.split(',').collect();
+                values.resize(4, "0");
+                format!("[{}]", values.join(", "))
+            }
+
+            as_enum.write_all(b"impl DrmFourcc {\n")?;
+            as_enum.write_all(b"pub(crate) const fn layout(self) -> Option<FormatInfo> {\n")?;
+            as_enum.write_all(b"match self {\n")?;
+
+            for (_, short) in names {
+                let member = enum_member_case(short);
+                match rows[short].as_slice() {
+                    ["-"] => writeln!(as_enum, "Self::{} => None,", member)?,
+                    [cpp, block_width, block_height, hsub, vsub, alpha, yuv] => writeln!(
+                        as_enum,
+                        "Self::{} => Some(FormatInfo::new({}, {}, {}, ({}, {}), {}, {})),",
+                        member,
+                        planes(cpp),
+                        planes(block_width),
+                        planes(block_height),
+                        hsub,
+                        vsub,
+                        *alpha == "1",
+                        *yuv == "1",
+                    )?,
+                    _ => return Err(format!("malformed layout for {}", short).into()),
+                }
+            }
+
             as_enum.write_all(b"}}}\n")?;
 
             Ok(())
         }
 
+        // FFmpeg pixel formats with the same layout, from the hand-maintained table
+        fn write_ffmpeg_pix_fmts(
+            as_enum: &mut File,
+            names: &[(&str, &str)],
+        ) -> Result<(), Box<dyn Error + Sync + Send>> {
+            let table = std::fs::read_to_string(FFMPEG_PIX_FMTS_PATH)?;
+            let mut seen = HashSet::new();
+
+            as_enum.write_all(b"pub(crate) const FFMPEG_PIX_FMTS: &[(DrmFourcc, &str)] = &[\n")?;
+
+            for line in table.lines() {
+                if line.trim().is_empty() || line.starts_with('#') {
+                    continue;
+                }
+
+                let (short, ffmpeg) = match line.split_whitespace().collect::<Vec<_>>()[..] {
+                    [short, ffmpeg] => (short, ffmpeg),
+                    _ => return Err(format!("malformed FFmpeg pixel format: {}", line).into()),
+                };
+                if !names.iter().any(|(_, name)| *name == short) {
+                    return Err(format!("{} is not a DRM format", short).into());
+                }
+                if !seen.insert(ffmpeg) {
+                    return Err(format!("{} appears twice", ffmpeg).into());
+                }
+
+                writeln!(
+                    as_enum,
+                    "(DrmFourcc::{}, \"{}\"),",
+                    enum_member_case(short),
+                    ffmpeg
+                )?;
+            }
+
+            as_enum.write_all(b"];\n")?;
+
+            Ok(())
+        }
+
+        // Map between members and their kernel and short names
+        fn write_names(
+            as_enum: &mut File,
+            named: &[(String, &str, &str)],
+            fallible: bool,
+        ) -> Result<(), std::io::Error> {
+            if fallible {
+                as_enum.write_all(
+                    b"pub(crate) fn names(self) -> Option<(&'static str, &'static str)> {\n",
+                )?;
+            } else {
+                as_enum
+                    .write_all(b"pub(crate) fn names(self) -> (&'static str, &'static str) {\n")?;
+            }
+            as_enum.write_all(b"match self {\n")?;
+
+            for (member, full, short) in named {
+                if fallible {
+                    writeln!(
+                        as_enum,
+                        "Self::{} => Some((\"{}\", \"{}\")),",
+                        member, full, short
+                    )?;
+                } else {
+                    writeln!(
+                        as_enum,
+                        "Self::{} => (\"{}\", \"{}\"),",
+                        member, full, short
+                    )?;
+                }
+            }
+            if fallible {
+                as_enum.write_all(b"Self::Unrecognized(_) => None,\n")?;
+            }
+
+            as_enum.write_all(b"}}\n")?;
+            as_enum.write_all(b"pub(crate) fn from_any_name(name: &str) -> Option<Self> {\n")?;
+            as_enum.write_all(b"match name {\n")?;
+
+            for (member, full, short) in named {
+                writeln!(
+                    as_enum,
+                    "\"{}\" | \"{}\" => Some(Self::{}),",
+                    full, short, member
+                )?;
+            }
+            as_enum.write_all(b"_ => None,\n")?;
+
+            as_enum.write_all(b"}}\n")?;
+
+            Ok(())
+        }
+
         let as_enum_path = "src/as_enum.rs";
         {
             let mut as_enum = File::create(as_enum_path)?;
 
             as_enum.write_all(b"// Automatically generated by build.rs\n")?;
             as_enum.write_all(b"use crate::consts;")?;
+            as_enum.write_all(b"use crate::FormatInfo;")?;
 
-            write_enum(&mut as_enum, "DrmFourcc", "u32", format_names)?;
+            write_enum(&mut as_enum, "DrmFourcc", "u32", format_names.clone())?;
+            write_format_info(&mut as_enum, &format_names)?;
+            write_ffmpeg_pix_fmts(&mut as_enum, &format_names)?;
 
             as_enum.write_all(b"#[derive(Debug)]")?;
             write_enum(&mut as_enum, "DrmVendor", "u8", vendor_names)?;
@@ -270,7 +442,15 @@ mod generate {
             }
             as_enum.write_all(b"Self::Unrecognized(x) => x,\n")?;
 
-            as_enum.write_all(b"}}}\n")?;
+            as_enum.write_all(b"}}\n")?;
+
+            let named: Vec<(String, &str, &str)> = modifier_names
+                .iter()
+                .map(|(full, short)| (enum_member_case(short), *full, short.as_str()))
+                .collect();
+            write_names(&mut as_enum, &named, true)?;
+
+            as_enum.write_all(b"}\n")?;
         }
 
         Command::new("rustfmt").arg(as_enum_path).spawn()?.wait()?;
@@ -0,0 +1,308 @@
+//! Decoding of the parameterized AMD format modifiers, see `AMD_FMT_MOD` in `drm_fourcc.h`.
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+use crate::{DrmModifier, DrmVendor};
+
+const VENDOR_SHIFT: u32 = 56;
+
+const TILE_VERSION_SHIFT: u32 = 0;
+const TILE_VERSION_MASK: u64 = 0xff;
+const TILE_SHIFT: u32 = 8;
+const TILE_MASK: u64 = 0x1f;
+const DCC_SHIFT: u32 = 13;
+const DCC_RETILE_SHIFT: u32 = 14;
+const DCC_PIPE_ALIGN_SHIFT: u32 = 15;
+const DCC_INDEPENDENT_64B_SHIFT: u32 = 16;
+const DCC_INDEPENDENT_128B_SHIFT: u32 = 17;
+const DCC_MAX_COMPRESSED_BLOCK_SHIFT: u32 = 18;
+const DCC_MAX_COMPRESSED_BLOCK_MASK: u64 = 0x3;
+const DCC_CONSTANT_ENCODE_SHIFT: u32 = 20;
+const PIPE_XOR_BITS_SHIFT: u32 = 21;
+const PIPE_XOR_BITS_MASK: u64 = 0x7;
+const BANK_XOR_BITS_SHIFT: u32 = 24;
+const BANK_XOR_BITS_MASK: u64 = 0x7;
+const PACKERS_SHIFT: u32 = 27;
+const PACKERS_MASK: u64 = 0x7;
+const RB_SHIFT: u32 = 30;
+const RB_MASK: u64 = 0x7;
+const PIPE_SHIFT: u32 = 33;
+const PIPE_MASK: u64 = 0x7;
+
+/// Bits 36 to 55 are reserved and must be zero.
+const RESERVED_MASK: u64 = ((1 << VENDOR_SHIFT) - 1) & !((1 << 36) - 1);
+
+/// The fields of an AMD format modifier.
+///
+/// Fields hold the raw values from the modifier. Values too large for their bit field are
+/// truncated when converting back to a [`DrmModifier`].
+///
+/// ```
+/// # use drm_fourcc::{AmdModifier, DrmModifier};
+/// let modifier = DrmModifier::from(0x0200_0000_0000_1b02);
+/// let amd = modifier.amd().unwrap();
+///
+/// assert_eq!(amd.tile_version, AmdModifier::TILE_VER_GFX10);
+/// assert_eq!(amd.tile, AmdModifier::TILE_GFX9_64K_R_X);
+/// assert!(!amd.dcc);
+/// assert_eq!(DrmModifier::from(amd), modifier);
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AmdModifier {
+    /// Tiling generation, one of the `TILE_VER_*` constants
+    pub tile_version: u8,
+    /// Swizzle mode, one of the `TILE_*` constants
+    pub tile: u8,
+    /// Delta Color Compression is enabled
+    pub dcc: bool,
+    /// A displayable copy of the DCC metadata is stored in a separate plane
+    pub dcc_retile: bool,
+    /// DCC metadata is aligned to the pipe configuration
+    pub dcc_pipe_align: bool,
+    /// DCC blocks of 64 bytes are compressed independently
+    pub dcc_independent_64b: bool,
+    /// DCC blocks of 128 bytes are compressed independently
+    pub dcc_independent_128b: bool,
+    /// Maximum DCC compressed block size, one of the `DCC_BLOCK_*` constants
+    pub dcc_max_compressed_block: u8,
+    /// DCC constant encoding is supported
+    pub dcc_constant_encode: bool,
+    /// log2 of the number of pipes used in address swizzling
+    pub pipe_xor_bits: u8,
+    /// log2 of the number of banks used in address swizzling
+    pub bank_xor_bits: u8,
+    /// log2 of the number of packers (GFX10.3 and later)
+    pub packers: u8,
+    /// log2 of the number of render backends (GFX9 DCC only)
+    pub rb: u8,
+    /// log2 of the number of pipes (GFX9 DCC only)
+    pub pipe: u8,
+}
+
+impl AmdModifier {
+    pub const TILE_VER_GFX9: u8 = 1;
+    pub const TILE_VER_GFX10: u8 = 2;
+    pub const TILE_VER_GFX10_RBPLUS: u8 = 3;
+    pub const TILE_VER_GFX11: u8 = 4;
+
+    pub const TILE_GFX9_64K_S: u8 = 9;
+    pub const TILE_GFX9_64K_D: u8 = 10;
+    pub const TILE_GFX9_64K_S_X: u8 = 25;
+    pub const TILE_GFX9_64K_D_X: u8 = 26;
+    pub const TILE_GFX9_64K_R_X: u8 = 27;
+    pub const TILE_GFX11_256K_R_X: u8 = 31;
+
+    pub const DCC_BLOCK_64B: u8 = 0;
+    pub const DCC_BLOCK_128B: u8 = 1;
+    pub const DCC_BLOCK_256B: u8 = 2;
+
+    /// Decode an AMD modifier from its u64 form.
+    ///
+    /// Returns `None` if the vendor isn't [`DrmVendor::Amd`] or any reserved bit is set, since such
+    /// a modifier couldn't be encoded back to the same value.
+    pub fn from_u64(value: u64) -> Option<Self> {
+        if (value >> VENDOR_SHIFT) as u8 != DrmVendor::Amd as u8 || value & RESERVED_MASK != 0 {
+            return None;
+        }
+
+        let field = |shift: u32, mask: u64| ((value >> shift) & mask) as u8;
+        let flag = |shift: u32| (value >> shift) & 1 != 0;
+
+        Some(AmdModifier {
+            tile_version: field(TILE_VERSION_SHIFT, TILE_VERSION_MASK),
+            tile: field(TILE_SHIFT, TILE_MASK),
+            dcc: flag(DCC_SHIFT),
+            dcc_retile: flag(DCC_RETILE_SHIFT),
+            dcc_pipe_align: flag(DCC_PIPE_ALIGN_SHIFT),
+            dcc_independent_64b: flag(DCC_INDEPENDENT_64B_SHIFT),
+            dcc_independent_128b: flag(DCC_INDEPENDENT_128B_SHIFT),
+            dcc_max_compressed_block: field(
+                DCC_MAX_COMPRESSED_BLOCK_SHIFT,
+                DCC_MAX_COMPRESSED_BLOCK_MASK,
+            ),
+            dcc_constant_encode: flag(DCC_CONSTANT_ENCODE_SHIFT),
+            pipe_xor_bits: field(PIPE_XOR_BITS_SHIFT, PIPE_XOR_BITS_MASK),
+            bank_xor_bits: field(BANK_XOR_BITS_SHIFT, BANK_XOR_BITS_MASK),
+            packers: field(PACKERS_SHIFT, PACKERS_MASK),
+            rb: field(RB_SHIFT, RB_MASK),
+            pipe: field(PIPE_SHIFT, PIPE_MASK),
+        })
+    }
+
+    /// Encode the modifier to its u64 form.
+    pub fn into_u64(self) -> u64 {
+        let field = |value: u8, shift: u32, mask: u64| (u64::from(value) & mask) << shift;
+        let flag = |value: bool, shift: u32| u64::from(value) << shift;
+
+        (u64::from(DrmVendor::Amd as u8) << VENDOR_SHIFT)
+            | field(self.tile_version, TILE_VERSION_SHIFT, TILE_VERSION_MASK)
+            | field(self.tile, TILE_SHIFT, TILE_MASK)
+            | flag(self.dcc, DCC_SHIFT)
+            | flag(self.dcc_retile, DCC_RETILE_SHIFT)
+            | flag(self.dcc_pipe_align, DCC_PIPE_ALIGN_SHIFT)
+            | flag(self.dcc_independent_64b, DCC_INDEPENDENT_64B_SHIFT)
+            | flag(self.dcc_independent_128b, DCC_INDEPENDENT_128B_SHIFT)
+            | field(
+                self.dcc_max_compressed_block,
+                DCC_MAX_COMPRESSED_BLOCK_SHIFT,
+                DCC_MAX_COMPRESSED_BLOCK_MASK,
+            )
+            | flag(self.dcc_constant_encode, DCC_CONSTANT_ENCODE_SHIFT)
+            | field(self.pipe_xor_bits, PIPE_XOR_BITS_SHIFT, PIPE_XOR_BITS_MASK)
+            | field(self.bank_xor_bits, BANK_XOR_BITS_SHIFT, BANK_XOR_BITS_MASK)
+            | field(self.packers, PACKERS_SHIFT, PACKERS_MASK)
+            | field(self.rb, RB_SHIFT, RB_MASK)
+            | field(self.pipe, PIPE_SHIFT, PIPE_MASK)
+    }
+}
+
+impl Display for AmdModifier {
+    /// Format in the style of `drm_info`, listing only the flags that are set and the fields that
+    /// are non-zero
+    ///
+    /// ```
+    /// # use drm_fourcc::AmdModifier;
+    /// let amd = AmdModifier {
+    ///     tile_version: AmdModifier::TILE_VER_GFX10,
+    ///     tile: AmdModifier::TILE_GFX9_64K_R_X,
+    ///     dcc: true,
+    ///     dcc_independent_64b: true,
+    ///     pipe_xor_bits: 3,
+    ///     ..AmdModifier::default()
+    /// };
+    /// assert_eq!(
+    ///     amd.to_string(),
+    ///     "AMD(GFX10, 64K_R_X, DCC, DCC_INDEPENDENT_64B, DCC_MAX_COMPRESSED_BLOCK=64B, PIPE_XOR_BITS=3)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let tile_version = match self.tile_version {
+            Self::TILE_VER_GFX9 => Some("GFX9"),
+            Self::TILE_VER_GFX10 => Some("GFX10"),
+            Self::TILE_VER_GFX10_RBPLUS => Some("GFX10_RBPLUS"),
+            Self::TILE_VER_GFX11 => Some("GFX11"),
+            _ => None,
+        };
+        match tile_version {
+            Some(name) => write!(f, "AMD({}", name)?,
+            None => write!(f, "AMD(TILE_VERSION={}", self.tile_version)?,
+        }
+
+        let tile = match self.tile {
+            Self::TILE_GFX9_64K_S => Some("64K_S"),
+            Self::TILE_GFX9_64K_D => Some("64K_D"),
+            Self::TILE_GFX9_64K_S_X => Some("64K_S_X"),
+            Self::TILE_GFX9_64K_D_X => Some("64K_D_X"),
+            Self::TILE_GFX9_64K_R_X => Some("64K_R_X"),
+            Self::TILE_GFX11_256K_R_X => Some("256K_R_X"),
+            _ => None,
+        };
+        match tile {
+            Some(name) => write!(f, ", {}", name)?,
+            None => write!(f, ", TILE={}", self.tile)?,
+        }
+
+        let flags = [
+            (self.dcc, "DCC"),
+            (self.dcc_retile, "DCC_RETILE"),
+            (self.dcc_pipe_align, "DCC_PIPE_ALIGN"),
+            (self.dcc_independent_64b, "DCC_INDEPENDENT_64B"),
+            (self.dcc_independent_128b, "DCC_INDEPENDENT_128B"),
+        ];
+        for (_, name) in flags.iter().filter(|(set, _)| *set) {
+            write!(f, ", {}", name)?;
+        }
+
+        if self.dcc {
+            match self.dcc_max_compressed_block {
+                Self::DCC_BLOCK_64B => f.write_str(", DCC_MAX_COMPRESSED_BLOCK=64B")?,
+                Self::DCC_BLOCK_128B => f.write_str(", DCC_MAX_COMPRESSED_BLOCK=128B")?,
+                Self::DCC_BLOCK_256B => f.write_str(", DCC_MAX_COMPRESSED_BLOCK=256B")?,
+                block => write!(f, ", DCC_MAX_COMPRESSED_BLOCK={}", block)?,
+            }
+        }
+        if self.dcc_constant_encode {
+            f.write_str(", DCC_CONSTANT_ENCODE")?;
+        }
+
+        let fields = [
+            (self.pipe_xor_bits, "PIPE_XOR_BITS"),
+            (self.bank_xor_bits, "BANK_XOR_BITS"),
+            (self.packers, "PACKERS"),
+            (self.rb, "RB"),
+            (self.pipe, "PIPE"),
+        ];
+        for (value, name) in fields.iter().filter(|(value, _)| *value != 0) {
+            write!(f, ", {}={}", name, value)?;
+        }
+
+        f.write_str(")")
+    }
+}
+
+impl From<AmdModifier> for u64 {
+    fn from(val: AmdModifier) -> u64 {
+        val.into_u64()
+    }
+}
+
+impl From<AmdModifier> for DrmModifier {
+    fn from(val: AmdModifier) -> DrmModifier {
+        DrmModifier::from(val.into_u64())
+    }
+}
+
+impl DrmModifier {
+    /// Decode the fields of an AMD modifier
+    ///
+    /// Returns `None` if the vendor isn't [`DrmVendor::Amd`] or reserved bits are set.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmModifier;
+    /// assert!(DrmModifier::from(0x0200_0000_0000_0901).amd().is_some());
+    /// assert!(DrmModifier::Linear.amd().is_none());
+    /// ```
+    pub fn amd(&self) -> Option<AmdModifier> {
+        AmdModifier::from_u64(self.into_u64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_gfx9_dcc() {
+        // GFX9 64K_S_X with DCC using independent 64B blocks.
+        let value = 0x0200_0002_4081_3901;
+        let amd = AmdModifier::from_u64(value).unwrap();
+
+        assert_eq!(amd.tile_version, AmdModifier::TILE_VER_GFX9);
+        assert_eq!(amd.tile, AmdModifier::TILE_GFX9_64K_S_X);
+        assert!(amd.dcc);
+        assert!(amd.dcc_independent_64b);
+        assert!(!amd.dcc_independent_128b);
+        assert_eq!(amd.dcc_max_compressed_block, AmdModifier::DCC_BLOCK_64B);
+        assert_eq!(amd.pipe_xor_bits, 4);
+        assert_eq!(amd.bank_xor_bits, 0);
+        assert_eq!(amd.rb, 1);
+        assert_eq!(amd.pipe, 1);
+        assert_eq!(amd.into_u64(), value);
+    }
+
+    #[test]
+    fn rejects_other_vendors_and_reserved_bits() {
+        assert_eq!(AmdModifier::from_u64(0x0100_0000_0000_0001), None);
+        assert_eq!(AmdModifier::from_u64(0x0200_0010_0000_0000), None);
+    }
+
+    #[test]
+    fn truncates_oversized_fields() {
+        let amd = AmdModifier {
+            tile: 0xff,
+            ..AmdModifier::default()
+        };
+        assert_eq!(amd.into_u64(), 0x0200_0000_0000_1f00);
+    }
+}
@@ -0,0 +1,118 @@
+//! Mapping of the Android `HAL_PIXEL_FORMAT_*` and `AHARDWAREBUFFER_FORMAT_*` codes, which share
+//! their values, to [`DrmFourcc`].
+//!
+//! Android names formats by memory byte order: `HAL_PIXEL_FORMAT_RGBA_8888` is
+//! [`DrmFourcc::Abgr8888`].
+use crate::DrmFourcc;
+
+const HAL_PIXEL_FORMAT_RGBA_8888: u32 = 0x1;
+const HAL_PIXEL_FORMAT_RGBX_8888: u32 = 0x2;
+const HAL_PIXEL_FORMAT_RGB_888: u32 = 0x3;
+const HAL_PIXEL_FORMAT_RGB_565: u32 = 0x4;
+const HAL_PIXEL_FORMAT_BGRA_8888: u32 = 0x5;
+const HAL_PIXEL_FORMAT_YCBCR_422_SP: u32 = 0x10;
+const HAL_PIXEL_FORMAT_YCRCB_420_SP: u32 = 0x11;
+const HAL_PIXEL_FORMAT_YCBCR_422_I: u32 = 0x14;
+const HAL_PIXEL_FORMAT_RGBA_FP16: u32 = 0x16;
+const HAL_PIXEL_FORMAT_YCBCR_420_888: u32 = 0x23;
+const HAL_PIXEL_FORMAT_RGBA_1010102: u32 = 0x2B;
+const HAL_PIXEL_FORMAT_YCBCR_P010: u32 = 0x36;
+const HAL_PIXEL_FORMAT_Y8: u32 = 0x2020_3859;
+const HAL_PIXEL_FORMAT_Y16: u32 = 0x2036_3159;
+const HAL_PIXEL_FORMAT_YV12: u32 = 0x3231_5659;
+
+const AHARDWAREBUFFER_FORMAT_R8_UNORM: u32 = 0x38;
+
+/// Every supported code and the formats it can be. The first entry listing a format is the code
+/// it maps to.
+const ANDROID_FORMATS: &[(u32, &[DrmFourcc])] = &[
+    (HAL_PIXEL_FORMAT_RGBA_8888, &[DrmFourcc::Abgr8888]),
+    (HAL_PIXEL_FORMAT_RGBX_8888, &[DrmFourcc::Xbgr8888]),
+    (HAL_PIXEL_FORMAT_RGB_888, &[DrmFourcc::Bgr888]),
+    (HAL_PIXEL_FORMAT_RGB_565, &[DrmFourcc::Rgb565]),
+    (HAL_PIXEL_FORMAT_BGRA_8888, &[DrmFourcc::Argb8888]),
+    (HAL_PIXEL_FORMAT_RGBA_FP16, &[DrmFourcc::Abgr16161616f]),
+    (HAL_PIXEL_FORMAT_RGBA_1010102, &[DrmFourcc::Abgr2101010]),
+    (AHARDWAREBUFFER_FORMAT_R8_UNORM, &[DrmFourcc::R8]),
+    (HAL_PIXEL_FORMAT_Y8, &[DrmFourcc::R8]),
+    (HAL_PIXEL_FORMAT_Y16, &[DrmFourcc::R16]),
+    (HAL_PIXEL_FORMAT_YCBCR_422_SP, &[DrmFourcc::Nv16]),
+    (HAL_PIXEL_FORMAT_YCRCB_420_SP, &[DrmFourcc::Nv21]),
+    (HAL_PIXEL_FORMAT_YCBCR_422_I, &[DrmFourcc::Yuyv]),
+    (HAL_PIXEL_FORMAT_YV12, &[DrmFourcc::Yvu420]),
+    (HAL_PIXEL_FORMAT_YCBCR_P010, &[DrmFourcc::P010]),
+    // A flexible format, the gralloc implementation picks the layout.
+    (
+        HAL_PIXEL_FORMAT_YCBCR_420_888,
+        &[DrmFourcc::Nv12, DrmFourcc::Yvu420, DrmFourcc::Nv21],
+    ),
+];
+
+impl DrmFourcc {
+    /// The formats an Android format code can be.
+    ///
+    /// Most codes describe exactly one format, but `HAL_PIXEL_FORMAT_YCBCR_420_888` lets gralloc
+    /// choose the layout, so every candidate is returned with the most common first. Unknown
+    /// codes return an empty slice.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// # const HAL_PIXEL_FORMAT_RGBA_8888: u32 = 0x1;
+    /// # const HAL_PIXEL_FORMAT_YCBCR_420_888: u32 = 0x23;
+    /// assert_eq!(
+    ///     DrmFourcc::from_android_format(HAL_PIXEL_FORMAT_RGBA_8888),
+    ///     [DrmFourcc::Abgr8888]
+    /// );
+    /// assert_eq!(
+    ///     DrmFourcc::from_android_format(HAL_PIXEL_FORMAT_YCBCR_420_888)[..2],
+    ///     [DrmFourcc::Nv12, DrmFourcc::Yvu420]
+    /// );
+    /// assert!(DrmFourcc::from_android_format(0).is_empty());
+    /// ```
+    pub fn from_android_format(format: u32) -> &'static [DrmFourcc] {
+        ANDROID_FORMATS
+            .iter()
+            .find(|&&(android, _)| android == format)
+            .map_or(&[], |&(_, candidates)| candidates)
+    }
+
+    /// The Android format code describing this format.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// # const AHARDWAREBUFFER_FORMAT_B8G8R8A8_UNORM: u32 = 0x5;
+    /// # const HAL_PIXEL_FORMAT_YV12: u32 = 0x3231_5659;
+    /// # const HAL_PIXEL_FORMAT_YCBCR_420_888: u32 = 0x23;
+    /// assert_eq!(
+    ///     DrmFourcc::Argb8888.to_android_format(),
+    ///     Some(AHARDWAREBUFFER_FORMAT_B8G8R8A8_UNORM)
+    /// );
+    /// assert_eq!(DrmFourcc::Yvu420.to_android_format(), Some(HAL_PIXEL_FORMAT_YV12));
+    /// assert_eq!(
+    ///     DrmFourcc::Nv12.to_android_format(),
+    ///     Some(HAL_PIXEL_FORMAT_YCBCR_420_888)
+    /// );
+    /// ```
+    pub fn to_android_format(&self) -> Option<u32> {
+        ANDROID_FORMATS
+            .iter()
+            .find(|(_, candidates)| candidates.contains(self))
+            .map(|&(android, _)| android)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        for &(android, candidates) in ANDROID_FORMATS {
+            for code in candidates {
+                let preferred = code.to_android_format().unwrap();
+                assert!(DrmFourcc::from_android_format(preferred).contains(code));
+            }
+            assert_eq!(DrmFourcc::from_android_format(android), candidates);
+        }
+    }
+}
@@ -0,0 +1,426 @@
+//! Decoding of ARM Frame Buffer Compression modifiers, see `DRM_FORMAT_MOD_ARM_AFBC` in
+//! `drm_fourcc.h`.
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{DrmModifier, DrmVendor};
+
+const VENDOR_SHIFT: u32 = 56;
+const TYPE_SHIFT: u32 = 52;
+const TYPE_MASK: u64 = 0xf;
+const TYPE_AFBC: u64 = 0x00;
+const VALUE_MASK: u64 = 0x000f_ffff_ffff_ffff;
+
+const BLOCK_SIZE_MASK: u64 = 0xf;
+
+/// Size of an AFBC superblock in pixels
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum AfbcBlockSize {
+    Size16x16 = 1,
+    Size32x8 = 2,
+    Size64x4 = 3,
+    /// 32x8 superblocks for the luma plane and 64x4 for the chroma plane
+    Size32x8_64x4 = 4,
+}
+
+impl AfbcBlockSize {
+    fn from_u8(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::Size16x16),
+            2 => Some(Self::Size32x8),
+            3 => Some(Self::Size64x4),
+            4 => Some(Self::Size32x8_64x4),
+            _ => None,
+        }
+    }
+}
+
+impl Display for AfbcBlockSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Size16x16 => "16x16",
+            Self::Size32x8 => "32x8",
+            Self::Size64x4 => "64x4",
+            Self::Size32x8_64x4 => "32x8_64x4",
+        })
+    }
+}
+
+/// The parameters of an ARM AFBC modifier.
+///
+/// ```
+/// # use drm_fourcc::{AfbcBlockSize, ArmAfbcModifier, DrmModifier};
+/// # use std::convert::TryFrom;
+/// let afbc = ArmAfbcModifier {
+///     sparse: true,
+///     ytr: true,
+///     ..ArmAfbcModifier::new(AfbcBlockSize::Size16x16)
+/// };
+/// let modifier = DrmModifier::try_from(afbc).unwrap();
+///
+/// assert_eq!(u64::from(modifier), 0x0800_0000_0000_0051);
+/// assert_eq!(modifier.arm_afbc(), Some(afbc));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArmAfbcModifier {
+    /// Superblock size
+    pub block_size: AfbcBlockSize,
+    /// Lossless color transform from RGB to YCbCr
+    pub ytr: bool,
+    /// The payload of each superblock is split in two halves
+    pub split: bool,
+    /// Superblock payloads are placed at fixed offsets, allowing partial updates
+    pub sparse: bool,
+    /// Chroma planes use copy-block restrict mode
+    pub cbr: bool,
+    /// Superblocks are grouped into tiles
+    pub tiled: bool,
+    /// Solid color blocks are used
+    pub sc: bool,
+    /// Double buffering of the header is used
+    pub db: bool,
+    /// Buffer content hints are provided
+    pub bch: bool,
+    /// Every superblock uses the uncompressed storage mode
+    pub usm: bool,
+}
+
+impl ArmAfbcModifier {
+    pub const YTR: u64 = 1 << 4;
+    pub const SPLIT: u64 = 1 << 5;
+    pub const SPARSE: u64 = 1 << 6;
+    pub const CBR: u64 = 1 << 7;
+    pub const TILED: u64 = 1 << 8;
+    pub const SC: u64 = 1 << 9;
+    pub const DB: u64 = 1 << 10;
+    pub const BCH: u64 = 1 << 11;
+    pub const USM: u64 = 1 << 12;
+
+    const KNOWN_FLAGS: u64 = Self::YTR
+        | Self::SPLIT
+        | Self::SPARSE
+        | Self::CBR
+        | Self::TILED
+        | Self::SC
+        | Self::DB
+        | Self::BCH
+        | Self::USM;
+
+    /// An AFBC modifier with the given block size and no flags set.
+    pub fn new(block_size: AfbcBlockSize) -> Self {
+        ArmAfbcModifier {
+            block_size,
+            ytr: false,
+            split: false,
+            sparse: false,
+            cbr: false,
+            tiled: false,
+            sc: false,
+            db: false,
+            bch: false,
+            usm: false,
+        }
+    }
+
+    /// Build from the AFBC mode, the bits passed to `DRM_FORMAT_MOD_ARM_AFBC`.
+    ///
+    /// ```
+    /// # use drm_fourcc::{AfbcBlockSize, ArmAfbcModifier, InvalidAfbcModifier};
+    /// let afbc = ArmAfbcModifier::from_flags(3 | ArmAfbcModifier::SPARSE).unwrap();
+    /// assert_eq!(afbc.block_size, AfbcBlockSize::Size64x4);
+    /// assert!(afbc.sparse);
+    ///
+    /// assert_eq!(
+    ///     ArmAfbcModifier::from_flags(1 | ArmAfbcModifier::SPLIT),
+    ///     Err(InvalidAfbcModifier::SplitWithoutSparse)
+    /// );
+    /// ```
+    pub fn from_flags(flags: u64) -> Result<Self, InvalidAfbcModifier> {
+        let block_size = (flags & BLOCK_SIZE_MASK) as u8;
+        let block_size = AfbcBlockSize::from_u8(block_size)
+            .ok_or(InvalidAfbcModifier::UnknownBlockSize(block_size))?;
+
+        let unknown = flags & !(BLOCK_SIZE_MASK | Self::KNOWN_FLAGS);
+        if unknown != 0 {
+            return Err(InvalidAfbcModifier::UnknownFlags(unknown));
+        }
+
+        let afbc = ArmAfbcModifier {
+            block_size,
+            ytr: flags & Self::YTR != 0,
+            split: flags & Self::SPLIT != 0,
+            sparse: flags & Self::SPARSE != 0,
+            cbr: flags & Self::CBR != 0,
+            tiled: flags & Self::TILED != 0,
+            sc: flags & Self::SC != 0,
+            db: flags & Self::DB != 0,
+            bch: flags & Self::BCH != 0,
+            usm: flags & Self::USM != 0,
+        };
+        afbc.validate()?;
+
+        Ok(afbc)
+    }
+
+    /// The AFBC mode, the bits passed to `DRM_FORMAT_MOD_ARM_AFBC`.
+    pub fn flags(&self) -> u64 {
+        let flag = |set: bool, flag: u64| if set { flag } else { 0 };
+
+        self.block_size as u64
+            | flag(self.ytr, Self::YTR)
+            | flag(self.split, Self::SPLIT)
+            | flag(self.sparse, Self::SPARSE)
+            | flag(self.cbr, Self::CBR)
+            | flag(self.tiled, Self::TILED)
+            | flag(self.sc, Self::SC)
+            | flag(self.db, Self::DB)
+            | flag(self.bch, Self::BCH)
+            | flag(self.usm, Self::USM)
+    }
+
+    /// Check for combinations of flags that the AFBC specification does not allow.
+    ///
+    /// ```
+    /// # use drm_fourcc::{AfbcBlockSize, ArmAfbcModifier, InvalidAfbcModifier};
+    /// let afbc = ArmAfbcModifier {
+    ///     ytr: true,
+    ///     cbr: true,
+    ///     ..ArmAfbcModifier::new(AfbcBlockSize::Size16x16)
+    /// };
+    /// assert_eq!(afbc.validate(), Err(InvalidAfbcModifier::CbrWithYtr));
+    /// ```
+    pub fn validate(&self) -> Result<(), InvalidAfbcModifier> {
+        if self.split && !self.sparse {
+            return Err(InvalidAfbcModifier::SplitWithoutSparse);
+        }
+        // CBR only applies to YUV formats, YTR only to RGB ones.
+        if self.cbr && self.ytr {
+            return Err(InvalidAfbcModifier::CbrWithYtr);
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for ArmAfbcModifier {
+    /// Format in the style of `drm_info`, listing the flags that are set
+    ///
+    /// ```
+    /// # use drm_fourcc::{AfbcBlockSize, ArmAfbcModifier};
+    /// let afbc = ArmAfbcModifier {
+    ///     sparse: true,
+    ///     ytr: true,
+    ///     ..ArmAfbcModifier::new(AfbcBlockSize::Size16x16)
+    /// };
+    /// assert_eq!(afbc.to_string(), "ARM_AFBC(16x16, YTR, SPARSE)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ARM_AFBC({}", self.block_size)?;
+
+        let flags = [
+            (self.ytr, "YTR"),
+            (self.split, "SPLIT"),
+            (self.sparse, "SPARSE"),
+            (self.cbr, "CBR"),
+            (self.tiled, "TILED"),
+            (self.sc, "SC"),
+            (self.db, "DB"),
+            (self.bch, "BCH"),
+            (self.usm, "USM"),
+        ];
+        for (_, name) in flags.iter().filter(|(set, _)| *set) {
+            write!(f, ", {}", name)?;
+        }
+
+        f.write_str(")")
+    }
+}
+
+impl TryFrom<ArmAfbcModifier> for DrmModifier {
+    type Error = InvalidAfbcModifier;
+
+    /// Encode an AFBC modifier, after checking it with [`ArmAfbcModifier::validate`]
+    ///
+    /// ```
+    /// # use drm_fourcc::{AfbcBlockSize, ArmAfbcModifier, DrmModifier, InvalidAfbcModifier};
+    /// # use std::convert::TryFrom;
+    /// let afbc = ArmAfbcModifier {
+    ///     split: true,
+    ///     ..ArmAfbcModifier::new(AfbcBlockSize::Size32x8)
+    /// };
+    /// assert_eq!(
+    ///     DrmModifier::try_from(afbc),
+    ///     Err(InvalidAfbcModifier::SplitWithoutSparse)
+    /// );
+    /// ```
+    fn try_from(val: ArmAfbcModifier) -> Result<Self, Self::Error> {
+        val.validate()?;
+
+        Ok(DrmModifier::from(
+            (u64::from(DrmVendor::Arm as u8) << VENDOR_SHIFT)
+                | (TYPE_AFBC << TYPE_SHIFT)
+                | (val.flags() & VALUE_MASK),
+        ))
+    }
+}
+
+impl TryFrom<DrmModifier> for ArmAfbcModifier {
+    type Error = InvalidAfbcModifier;
+
+    /// Decode an AFBC modifier
+    ///
+    /// ```
+    /// # use drm_fourcc::{ArmAfbcModifier, DrmModifier, InvalidAfbcModifier};
+    /// # use std::convert::TryFrom;
+    /// assert_eq!(
+    ///     ArmAfbcModifier::try_from(DrmModifier::Linear),
+    ///     Err(InvalidAfbcModifier::NotAfbc(DrmModifier::Linear))
+    /// );
+    /// ```
+    fn try_from(value: DrmModifier) -> Result<Self, Self::Error> {
+        let raw = u64::from(value);
+        if (raw >> VENDOR_SHIFT) as u8 != DrmVendor::Arm as u8
+            || (raw >> TYPE_SHIFT) & TYPE_MASK != TYPE_AFBC
+        {
+            return Err(InvalidAfbcModifier::NotAfbc(value));
+        }
+
+        Self::from_flags(raw & VALUE_MASK)
+    }
+}
+
+impl DrmModifier {
+    /// Decode the parameters of an ARM AFBC modifier
+    ///
+    /// Returns `None` if this isn't a valid AFBC modifier, use [`ArmAfbcModifier::try_from`] to
+    /// find out why.
+    ///
+    /// ```
+    /// # use drm_fourcc::{AfbcBlockSize, DrmModifier};
+    /// let afbc = DrmModifier::from(0x0800_0000_0000_0002).arm_afbc().unwrap();
+    /// assert_eq!(afbc.block_size, AfbcBlockSize::Size32x8);
+    ///
+    /// assert!(DrmModifier::Linear.arm_afbc().is_none());
+    /// ```
+    pub fn arm_afbc(&self) -> Option<ArmAfbcModifier> {
+        ArmAfbcModifier::try_from(*self).ok()
+    }
+}
+
+/// Reasons a modifier isn't a valid ARM AFBC modifier
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InvalidAfbcModifier {
+    /// The modifier isn't from ARM, or isn't of the AFBC type
+    NotAfbc(DrmModifier),
+    /// The block size field doesn't hold a known block size
+    UnknownBlockSize(u8),
+    /// Bits we don't know about are set
+    UnknownFlags(u64),
+    /// `SPLIT` may only be used together with `SPARSE`
+    SplitWithoutSparse,
+    /// `CBR` is for YUV formats and `YTR` for RGB formats, so they can't be combined
+    CbrWithYtr,
+}
+
+impl Display for InvalidAfbcModifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidAfbcModifier {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_flag() {
+        let flags = 4 | (ArmAfbcModifier::KNOWN_FLAGS & !ArmAfbcModifier::CBR);
+        let afbc = ArmAfbcModifier::from_flags(flags).unwrap();
+
+        assert_eq!(afbc.block_size, AfbcBlockSize::Size32x8_64x4);
+        assert!(afbc.ytr && afbc.split && afbc.sparse && !afbc.cbr);
+        assert!(afbc.tiled && afbc.sc && afbc.db && afbc.bch && afbc.usm);
+        assert_eq!(afbc.flags(), flags);
+
+        let modifier = DrmModifier::try_from(afbc).unwrap();
+        assert_eq!(u64::from(modifier), 0x0800_0000_0000_1f74);
+        assert_eq!(modifier.arm_afbc(), Some(afbc));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn round_trips_uncompressed_storage_mode() {
+        let modifier = DrmModifier::from(0x0800_0000_0000_1101);
+        let afbc = modifier.arm_afbc().unwrap();
+        assert!(afbc.tiled && afbc.usm);
+        assert_eq!(afbc.to_string(), "ARM_AFBC(16x16, TILED, USM)");
+        assert_eq!(DrmModifier::try_from(afbc), Ok(modifier));
+    }
+
+    #[test]
+    fn rejects_illegal_combinations() {
+        let afbc = ArmAfbcModifier::new(AfbcBlockSize::Size32x8);
+
+        let split = ArmAfbcModifier {
+            split: true,
+            ..afbc
+        };
+        assert_eq!(
+            ArmAfbcModifier::from_flags(2 | ArmAfbcModifier::SPLIT),
+            Err(InvalidAfbcModifier::SplitWithoutSparse)
+        );
+        assert_eq!(
+            DrmModifier::try_from(split),
+            Err(InvalidAfbcModifier::SplitWithoutSparse)
+        );
+
+        let cbr_ytr = ArmAfbcModifier {
+            cbr: true,
+            ytr: true,
+            ..afbc
+        };
+        assert_eq!(
+            ArmAfbcModifier::from_flags(2 | ArmAfbcModifier::CBR | ArmAfbcModifier::YTR),
+            Err(InvalidAfbcModifier::CbrWithYtr)
+        );
+        assert_eq!(
+            DrmModifier::try_from(cbr_ytr),
+            Err(InvalidAfbcModifier::CbrWithYtr)
+        );
+        assert_eq!(
+            ArmAfbcModifier::try_from(DrmModifier::from(0x0800_0000_0000_0092)),
+            Err(InvalidAfbcModifier::CbrWithYtr)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_bits() {
+        assert_eq!(
+            ArmAfbcModifier::from_flags(0),
+            Err(InvalidAfbcModifier::UnknownBlockSize(0))
+        );
+        assert_eq!(
+            ArmAfbcModifier::from_flags(1 | 1 << 20),
+            Err(InvalidAfbcModifier::UnknownFlags(1 << 20))
+        );
+    }
+
+    #[test]
+    fn rejects_other_arm_types() {
+        let misc = DrmModifier::from(0x0810_0000_0000_0001);
+        assert_eq!(
+            ArmAfbcModifier::try_from(misc),
+            Err(InvalidAfbcModifier::NotAfbc(misc))
+        );
+    }
+}
@@ -1,5 +1,6 @@
 // Automatically generated by build.rs
 use crate::consts;
+use crate::FormatInfo;
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
@@ -115,7 +116,7 @@ pub enum DrmFourcc {
     Yvyu = consts::DRM_FOURCC_YVYU,
 }
 impl DrmFourcc {
-    pub(crate) fn from_u32(n: u32) -> Option<Self> {
+    pub(crate) const fn from_u32(n: u32) -> Option<Self> {
         match n {
             consts::DRM_FOURCC_ABGR1555 => Some(Self::Abgr1555),
             consts::DRM_FOURCC_ABGR16161616 => Some(Self::Abgr16161616),
@@ -229,7 +230,1157 @@ impl DrmFourcc {
             _ => None,
         }
     }
+    pub(crate) fn names(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Abgr1555 => ("DRM_FORMAT_ABGR1555", "ABGR1555"),
+            Self::Abgr16161616 => ("DRM_FORMAT_ABGR16161616", "ABGR16161616"),
+            Self::Abgr16161616f => ("DRM_FORMAT_ABGR16161616F", "ABGR16161616F"),
+            Self::Abgr2101010 => ("DRM_FORMAT_ABGR2101010", "ABGR2101010"),
+            Self::Abgr4444 => ("DRM_FORMAT_ABGR4444", "ABGR4444"),
+            Self::Abgr8888 => ("DRM_FORMAT_ABGR8888", "ABGR8888"),
+            Self::Argb1555 => ("DRM_FORMAT_ARGB1555", "ARGB1555"),
+            Self::Argb16161616 => ("DRM_FORMAT_ARGB16161616", "ARGB16161616"),
+            Self::Argb16161616f => ("DRM_FORMAT_ARGB16161616F", "ARGB16161616F"),
+            Self::Argb2101010 => ("DRM_FORMAT_ARGB2101010", "ARGB2101010"),
+            Self::Argb4444 => ("DRM_FORMAT_ARGB4444", "ARGB4444"),
+            Self::Argb8888 => ("DRM_FORMAT_ARGB8888", "ARGB8888"),
+            Self::Axbxgxrx106106106106 => {
+                ("DRM_FORMAT_AXBXGXRX106106106106", "AXBXGXRX106106106106")
+            }
+            Self::Ayuv => ("DRM_FORMAT_AYUV", "AYUV"),
+            Self::Bgr233 => ("DRM_FORMAT_BGR233", "BGR233"),
+            Self::Bgr565 => ("DRM_FORMAT_BGR565", "BGR565"),
+            Self::Bgr565_a8 => ("DRM_FORMAT_BGR565_A8", "BGR565_A8"),
+            Self::Bgr888 => ("DRM_FORMAT_BGR888", "BGR888"),
+            Self::Bgr888_a8 => ("DRM_FORMAT_BGR888_A8", "BGR888_A8"),
+            Self::Bgra1010102 => ("DRM_FORMAT_BGRA1010102", "BGRA1010102"),
+            Self::Bgra4444 => ("DRM_FORMAT_BGRA4444", "BGRA4444"),
+            Self::Bgra5551 => ("DRM_FORMAT_BGRA5551", "BGRA5551"),
+            Self::Bgra8888 => ("DRM_FORMAT_BGRA8888", "BGRA8888"),
+            Self::Bgrx1010102 => ("DRM_FORMAT_BGRX1010102", "BGRX1010102"),
+            Self::Bgrx4444 => ("DRM_FORMAT_BGRX4444", "BGRX4444"),
+            Self::Bgrx5551 => ("DRM_FORMAT_BGRX5551", "BGRX5551"),
+            Self::Bgrx8888 => ("DRM_FORMAT_BGRX8888", "BGRX8888"),
+            Self::Bgrx8888_a8 => ("DRM_FORMAT_BGRX8888_A8", "BGRX8888_A8"),
+            Self::Big_endian => ("DRM_FORMAT_BIG_ENDIAN", "BIG_ENDIAN"),
+            Self::C8 => ("DRM_FORMAT_C8", "C8"),
+            Self::Gr1616 => ("DRM_FORMAT_GR1616", "GR1616"),
+            Self::Gr88 => ("DRM_FORMAT_GR88", "GR88"),
+            Self::Nv12 => ("DRM_FORMAT_NV12", "NV12"),
+            Self::Nv15 => ("DRM_FORMAT_NV15", "NV15"),
+            Self::Nv16 => ("DRM_FORMAT_NV16", "NV16"),
+            Self::Nv21 => ("DRM_FORMAT_NV21", "NV21"),
+            Self::Nv24 => ("DRM_FORMAT_NV24", "NV24"),
+            Self::Nv42 => ("DRM_FORMAT_NV42", "NV42"),
+            Self::Nv61 => ("DRM_FORMAT_NV61", "NV61"),
+            Self::P010 => ("DRM_FORMAT_P010", "P010"),
+            Self::P012 => ("DRM_FORMAT_P012", "P012"),
+            Self::P016 => ("DRM_FORMAT_P016", "P016"),
+            Self::P210 => ("DRM_FORMAT_P210", "P210"),
+            Self::Q401 => ("DRM_FORMAT_Q401", "Q401"),
+            Self::Q410 => ("DRM_FORMAT_Q410", "Q410"),
+            Self::R16 => ("DRM_FORMAT_R16", "R16"),
+            Self::R8 => ("DRM_FORMAT_R8", "R8"),
+            Self::Rg1616 => ("DRM_FORMAT_RG1616", "RG1616"),
+            Self::Rg88 => ("DRM_FORMAT_RG88", "RG88"),
+            Self::Rgb332 => ("DRM_FORMAT_RGB332", "RGB332"),
+            Self::Rgb565 => ("DRM_FORMAT_RGB565", "RGB565"),
+            Self::Rgb565_a8 => ("DRM_FORMAT_RGB565_A8", "RGB565_A8"),
+            Self::Rgb888 => ("DRM_FORMAT_RGB888", "RGB888"),
+            Self::Rgb888_a8 => ("DRM_FORMAT_RGB888_A8", "RGB888_A8"),
+            Self::Rgba1010102 => ("DRM_FORMAT_RGBA1010102", "RGBA1010102"),
+            Self::Rgba4444 => ("DRM_FORMAT_RGBA4444", "RGBA4444"),
+            Self::Rgba5551 => ("DRM_FORMAT_RGBA5551", "RGBA5551"),
+            Self::Rgba8888 => ("DRM_FORMAT_RGBA8888", "RGBA8888"),
+            Self::Rgbx1010102 => ("DRM_FORMAT_RGBX1010102", "RGBX1010102"),
+            Self::Rgbx4444 => ("DRM_FORMAT_RGBX4444", "RGBX4444"),
+            Self::Rgbx5551 => ("DRM_FORMAT_RGBX5551", "RGBX5551"),
+            Self::Rgbx8888 => ("DRM_FORMAT_RGBX8888", "RGBX8888"),
+            Self::Rgbx8888_a8 => ("DRM_FORMAT_RGBX8888_A8", "RGBX8888_A8"),
+            Self::Uyvy => ("DRM_FORMAT_UYVY", "UYVY"),
+            Self::Vuy101010 => ("DRM_FORMAT_VUY101010", "VUY101010"),
+            Self::Vuy888 => ("DRM_FORMAT_VUY888", "VUY888"),
+            Self::Vyuy => ("DRM_FORMAT_VYUY", "VYUY"),
+            Self::X0l0 => ("DRM_FORMAT_X0L0", "X0L0"),
+            Self::X0l2 => ("DRM_FORMAT_X0L2", "X0L2"),
+            Self::Xbgr1555 => ("DRM_FORMAT_XBGR1555", "XBGR1555"),
+            Self::Xbgr16161616 => ("DRM_FORMAT_XBGR16161616", "XBGR16161616"),
+            Self::Xbgr16161616f => ("DRM_FORMAT_XBGR16161616F", "XBGR16161616F"),
+            Self::Xbgr2101010 => ("DRM_FORMAT_XBGR2101010", "XBGR2101010"),
+            Self::Xbgr4444 => ("DRM_FORMAT_XBGR4444", "XBGR4444"),
+            Self::Xbgr8888 => ("DRM_FORMAT_XBGR8888", "XBGR8888"),
+            Self::Xbgr8888_a8 => ("DRM_FORMAT_XBGR8888_A8", "XBGR8888_A8"),
+            Self::Xrgb1555 => ("DRM_FORMAT_XRGB1555", "XRGB1555"),
+            Self::Xrgb16161616 => ("DRM_FORMAT_XRGB16161616", "XRGB16161616"),
+            Self::Xrgb16161616f => ("DRM_FORMAT_XRGB16161616F", "XRGB16161616F"),
+            Self::Xrgb2101010 => ("DRM_FORMAT_XRGB2101010", "XRGB2101010"),
+            Self::Xrgb4444 => ("DRM_FORMAT_XRGB4444", "XRGB4444"),
+            Self::Xrgb8888 => ("DRM_FORMAT_XRGB8888", "XRGB8888"),
+            Self::Xrgb8888_a8 => ("DRM_FORMAT_XRGB8888_A8", "XRGB8888_A8"),
+            Self::Xvyu12_16161616 => ("DRM_FORMAT_XVYU12_16161616", "XVYU12_16161616"),
+            Self::Xvyu16161616 => ("DRM_FORMAT_XVYU16161616", "XVYU16161616"),
+            Self::Xvyu2101010 => ("DRM_FORMAT_XVYU2101010", "XVYU2101010"),
+            Self::Xyuv8888 => ("DRM_FORMAT_XYUV8888", "XYUV8888"),
+            Self::Y0l0 => ("DRM_FORMAT_Y0L0", "Y0L0"),
+            Self::Y0l2 => ("DRM_FORMAT_Y0L2", "Y0L2"),
+            Self::Y210 => ("DRM_FORMAT_Y210", "Y210"),
+            Self::Y212 => ("DRM_FORMAT_Y212", "Y212"),
+            Self::Y216 => ("DRM_FORMAT_Y216", "Y216"),
+            Self::Y410 => ("DRM_FORMAT_Y410", "Y410"),
+            Self::Y412 => ("DRM_FORMAT_Y412", "Y412"),
+            Self::Y416 => ("DRM_FORMAT_Y416", "Y416"),
+            Self::Yuv410 => ("DRM_FORMAT_YUV410", "YUV410"),
+            Self::Yuv411 => ("DRM_FORMAT_YUV411", "YUV411"),
+            Self::Yuv420 => ("DRM_FORMAT_YUV420", "YUV420"),
+            Self::Yuv420_10bit => ("DRM_FORMAT_YUV420_10BIT", "YUV420_10BIT"),
+            Self::Yuv420_8bit => ("DRM_FORMAT_YUV420_8BIT", "YUV420_8BIT"),
+            Self::Yuv422 => ("DRM_FORMAT_YUV422", "YUV422"),
+            Self::Yuv444 => ("DRM_FORMAT_YUV444", "YUV444"),
+            Self::Yuyv => ("DRM_FORMAT_YUYV", "YUYV"),
+            Self::Yvu410 => ("DRM_FORMAT_YVU410", "YVU410"),
+            Self::Yvu411 => ("DRM_FORMAT_YVU411", "YVU411"),
+            Self::Yvu420 => ("DRM_FORMAT_YVU420", "YVU420"),
+            Self::Yvu422 => ("DRM_FORMAT_YVU422", "YVU422"),
+            Self::Yvu444 => ("DRM_FORMAT_YVU444", "YVU444"),
+            Self::Yvyu => ("DRM_FORMAT_YVYU", "YVYU"),
+        }
+    }
+    pub(crate) fn from_any_name(name: &str) -> Option<Self> {
+        match name {
+            "DRM_FORMAT_ABGR1555" | "ABGR1555" => Some(Self::Abgr1555),
+            "DRM_FORMAT_ABGR16161616" | "ABGR16161616" => Some(Self::Abgr16161616),
+            "DRM_FORMAT_ABGR16161616F" | "ABGR16161616F" => Some(Self::Abgr16161616f),
+            "DRM_FORMAT_ABGR2101010" | "ABGR2101010" => Some(Self::Abgr2101010),
+            "DRM_FORMAT_ABGR4444" | "ABGR4444" => Some(Self::Abgr4444),
+            "DRM_FORMAT_ABGR8888" | "ABGR8888" => Some(Self::Abgr8888),
+            "DRM_FORMAT_ARGB1555" | "ARGB1555" => Some(Self::Argb1555),
+            "DRM_FORMAT_ARGB16161616" | "ARGB16161616" => Some(Self::Argb16161616),
+            "DRM_FORMAT_ARGB16161616F" | "ARGB16161616F" => Some(Self::Argb16161616f),
+            "DRM_FORMAT_ARGB2101010" | "ARGB2101010" => Some(Self::Argb2101010),
+            "DRM_FORMAT_ARGB4444" | "ARGB4444" => Some(Self::Argb4444),
+            "DRM_FORMAT_ARGB8888" | "ARGB8888" => Some(Self::Argb8888),
+            "DRM_FORMAT_AXBXGXRX106106106106" | "AXBXGXRX106106106106" => {
+                Some(Self::Axbxgxrx106106106106)
+            }
+            "DRM_FORMAT_AYUV" | "AYUV" => Some(Self::Ayuv),
+            "DRM_FORMAT_BGR233" | "BGR233" => Some(Self::Bgr233),
+            "DRM_FORMAT_BGR565" | "BGR565" => Some(Self::Bgr565),
+            "DRM_FORMAT_BGR565_A8" | "BGR565_A8" => Some(Self::Bgr565_a8),
+            "DRM_FORMAT_BGR888" | "BGR888" => Some(Self::Bgr888),
+            "DRM_FORMAT_BGR888_A8" | "BGR888_A8" => Some(Self::Bgr888_a8),
+            "DRM_FORMAT_BGRA1010102" | "BGRA1010102" => Some(Self::Bgra1010102),
+            "DRM_FORMAT_BGRA4444" | "BGRA4444" => Some(Self::Bgra4444),
+            "DRM_FORMAT_BGRA5551" | "BGRA5551" => Some(Self::Bgra5551),
+            "DRM_FORMAT_BGRA8888" | "BGRA8888" => Some(Self::Bgra8888),
+            "DRM_FORMAT_BGRX1010102" | "BGRX1010102" => Some(Self::Bgrx1010102),
+            "DRM_FORMAT_BGRX4444" | "BGRX4444" => Some(Self::Bgrx4444),
+            "DRM_FORMAT_BGRX5551" | "BGRX5551" => Some(Self::Bgrx5551),
+            "DRM_FORMAT_BGRX8888" | "BGRX8888" => Some(Self::Bgrx8888),
+            "DRM_FORMAT_BGRX8888_A8" | "BGRX8888_A8" => Some(Self::Bgrx8888_a8),
+            "DRM_FORMAT_BIG_ENDIAN" | "BIG_ENDIAN" => Some(Self::Big_endian),
+            "DRM_FORMAT_C8" | "C8" => Some(Self::C8),
+            "DRM_FORMAT_GR1616" | "GR1616" => Some(Self::Gr1616),
+            "DRM_FORMAT_GR88" | "GR88" => Some(Self::Gr88),
+            "DRM_FORMAT_NV12" | "NV12" => Some(Self::Nv12),
+            "DRM_FORMAT_NV15" | "NV15" => Some(Self::Nv15),
+            "DRM_FORMAT_NV16" | "NV16" => Some(Self::Nv16),
+            "DRM_FORMAT_NV21" | "NV21" => Some(Self::Nv21),
+            "DRM_FORMAT_NV24" | "NV24" => Some(Self::Nv24),
+            "DRM_FORMAT_NV42" | "NV42" => Some(Self::Nv42),
+            "DRM_FORMAT_NV61" | "NV61" => Some(Self::Nv61),
+            "DRM_FORMAT_P010" | "P010" => Some(Self::P010),
+            "DRM_FORMAT_P012" | "P012" => Some(Self::P012),
+            "DRM_FORMAT_P016" | "P016" => Some(Self::P016),
+            "DRM_FORMAT_P210" | "P210" => Some(Self::P210),
+            "DRM_FORMAT_Q401" | "Q401" => Some(Self::Q401),
+            "DRM_FORMAT_Q410" | "Q410" => Some(Self::Q410),
+            "DRM_FORMAT_R16" | "R16" => Some(Self::R16),
+            "DRM_FORMAT_R8" | "R8" => Some(Self::R8),
+            "DRM_FORMAT_RG1616" | "RG1616" => Some(Self::Rg1616),
+            "DRM_FORMAT_RG88" | "RG88" => Some(Self::Rg88),
+            "DRM_FORMAT_RGB332" | "RGB332" => Some(Self::Rgb332),
+            "DRM_FORMAT_RGB565" | "RGB565" => Some(Self::Rgb565),
+            "DRM_FORMAT_RGB565_A8" | "RGB565_A8" => Some(Self::Rgb565_a8),
+            "DRM_FORMAT_RGB888" | "RGB888" => Some(Self::Rgb888),
+            "DRM_FORMAT_RGB888_A8" | "RGB888_A8" => Some(Self::Rgb888_a8),
+            "DRM_FORMAT_RGBA1010102" | "RGBA1010102" => Some(Self::Rgba1010102),
+            "DRM_FORMAT_RGBA4444" | "RGBA4444" => Some(Self::Rgba4444),
+            "DRM_FORMAT_RGBA5551" | "RGBA5551" => Some(Self::Rgba5551),
+            "DRM_FORMAT_RGBA8888" | "RGBA8888" => Some(Self::Rgba8888),
+            "DRM_FORMAT_RGBX1010102" | "RGBX1010102" => Some(Self::Rgbx1010102),
+            "DRM_FORMAT_RGBX4444" | "RGBX4444" => Some(Self::Rgbx4444),
+            "DRM_FORMAT_RGBX5551" | "RGBX5551" => Some(Self::Rgbx5551),
+            "DRM_FORMAT_RGBX8888" | "RGBX8888" => Some(Self::Rgbx8888),
+            "DRM_FORMAT_RGBX8888_A8" | "RGBX8888_A8" => Some(Self::Rgbx8888_a8),
+            "DRM_FORMAT_UYVY" | "UYVY" => Some(Self::Uyvy),
+            "DRM_FORMAT_VUY101010" | "VUY101010" => Some(Self::Vuy101010),
+            "DRM_FORMAT_VUY888" | "VUY888" => Some(Self::Vuy888),
+            "DRM_FORMAT_VYUY" | "VYUY" => Some(Self::Vyuy),
+            "DRM_FORMAT_X0L0" | "X0L0" => Some(Self::X0l0),
+            "DRM_FORMAT_X0L2" | "X0L2" => Some(Self::X0l2),
+            "DRM_FORMAT_XBGR1555" | "XBGR1555" => Some(Self::Xbgr1555),
+            "DRM_FORMAT_XBGR16161616" | "XBGR16161616" => Some(Self::Xbgr16161616),
+            "DRM_FORMAT_XBGR16161616F" | "XBGR16161616F" => Some(Self::Xbgr16161616f),
+            "DRM_FORMAT_XBGR2101010" | "XBGR2101010" => Some(Self::Xbgr2101010),
+            "DRM_FORMAT_XBGR4444" | "XBGR4444" => Some(Self::Xbgr4444),
+            "DRM_FORMAT_XBGR8888" | "XBGR8888" => Some(Self::Xbgr8888),
+            "DRM_FORMAT_XBGR8888_A8" | "XBGR8888_A8" => Some(Self::Xbgr8888_a8),
+            "DRM_FORMAT_XRGB1555" | "XRGB1555" => Some(Self::Xrgb1555),
+            "DRM_FORMAT_XRGB16161616" | "XRGB16161616" => Some(Self::Xrgb16161616),
+            "DRM_FORMAT_XRGB16161616F" | "XRGB16161616F" => Some(Self::Xrgb16161616f),
+            "DRM_FORMAT_XRGB2101010" | "XRGB2101010" => Some(Self::Xrgb2101010),
+            "DRM_FORMAT_XRGB4444" | "XRGB4444" => Some(Self::Xrgb4444),
+            "DRM_FORMAT_XRGB8888" | "XRGB8888" => Some(Self::Xrgb8888),
+            "DRM_FORMAT_XRGB8888_A8" | "XRGB8888_A8" => Some(Self::Xrgb8888_a8),
+            "DRM_FORMAT_XVYU12_16161616" | "XVYU12_16161616" => Some(Self::Xvyu12_16161616),
+            "DRM_FORMAT_XVYU16161616" | "XVYU16161616" => Some(Self::Xvyu16161616),
+            "DRM_FORMAT_XVYU2101010" | "XVYU2101010" => Some(Self::Xvyu2101010),
+            "DRM_FORMAT_XYUV8888" | "XYUV8888" => Some(Self::Xyuv8888),
+            "DRM_FORMAT_Y0L0" | "Y0L0" => Some(Self::Y0l0),
+            "DRM_FORMAT_Y0L2" | "Y0L2" => Some(Self::Y0l2),
+            "DRM_FORMAT_Y210" | "Y210" => Some(Self::Y210),
+            "DRM_FORMAT_Y212" | "Y212" => Some(Self::Y212),
+            "DRM_FORMAT_Y216" | "Y216" => Some(Self::Y216),
+            "DRM_FORMAT_Y410" | "Y410" => Some(Self::Y410),
+            "DRM_FORMAT_Y412" | "Y412" => Some(Self::Y412),
+            "DRM_FORMAT_Y416" | "Y416" => Some(Self::Y416),
+            "DRM_FORMAT_YUV410" | "YUV410" => Some(Self::Yuv410),
+            "DRM_FORMAT_YUV411" | "YUV411" => Some(Self::Yuv411),
+            "DRM_FORMAT_YUV420" | "YUV420" => Some(Self::Yuv420),
+            "DRM_FORMAT_YUV420_10BIT" | "YUV420_10BIT" => Some(Self::Yuv420_10bit),
+            "DRM_FORMAT_YUV420_8BIT" | "YUV420_8BIT" => Some(Self::Yuv420_8bit),
+            "DRM_FORMAT_YUV422" | "YUV422" => Some(Self::Yuv422),
+            "DRM_FORMAT_YUV444" | "YUV444" => Some(Self::Yuv444),
+            "DRM_FORMAT_YUYV" | "YUYV" => Some(Self::Yuyv),
+            "DRM_FORMAT_YVU410" | "YVU410" => Some(Self::Yvu410),
+            "DRM_FORMAT_YVU411" | "YVU411" => Some(Self::Yvu411),
+            "DRM_FORMAT_YVU420" | "YVU420" => Some(Self::Yvu420),
+            "DRM_FORMAT_YVU422" | "YVU422" => Some(Self::Yvu422),
+            "DRM_FORMAT_YVU444" | "YVU444" => Some(Self::Yvu444),
+            "DRM_FORMAT_YVYU" | "YVYU" => Some(Self::Yvyu),
+            _ => None,
+        }
+    }
+}
+impl DrmFourcc {
+    pub(crate) const fn layout(self) -> Option<FormatInfo> {
+        match self {
+            Self::Abgr1555 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Abgr16161616 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Abgr16161616f => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Abgr2101010 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Abgr4444 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Abgr8888 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Argb1555 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Argb16161616 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Argb16161616f => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Argb2101010 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Argb4444 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Argb8888 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Axbxgxrx106106106106 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Ayuv => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                true,
+            )),
+            Self::Bgr233 => Some(FormatInfo::new(
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Bgr565 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Bgr565_a8 => Some(FormatInfo::new(
+                [2, 1, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Bgr888 => Some(FormatInfo::new(
+                [3, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Bgr888_a8 => Some(FormatInfo::new(
+                [3, 1, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Bgra1010102 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Bgra4444 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Bgra5551 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Bgra8888 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Bgrx1010102 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Bgrx4444 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Bgrx5551 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Bgrx8888 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Bgrx8888_a8 => Some(FormatInfo::new(
+                [4, 1, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Big_endian => None,
+            Self::C8 => Some(FormatInfo::new(
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Gr1616 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Gr88 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Nv12 => Some(FormatInfo::new(
+                [1, 2, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::Nv15 => Some(FormatInfo::new(
+                [5, 5, 0, 0],
+                [4, 2, 0, 0],
+                [1, 1, 0, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::Nv16 => Some(FormatInfo::new(
+                [1, 2, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::Nv21 => Some(FormatInfo::new(
+                [1, 2, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::Nv24 => Some(FormatInfo::new(
+                [1, 2, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Nv42 => Some(FormatInfo::new(
+                [1, 2, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Nv61 => Some(FormatInfo::new(
+                [1, 2, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::P010 => Some(FormatInfo::new(
+                [2, 4, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::P012 => Some(FormatInfo::new(
+                [2, 4, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::P016 => Some(FormatInfo::new(
+                [2, 4, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::P210 => Some(FormatInfo::new(
+                [2, 4, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::Q401 => Some(FormatInfo::new(
+                [2, 2, 2, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Q410 => Some(FormatInfo::new(
+                [2, 2, 2, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::R16 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::R8 => Some(FormatInfo::new(
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Rg1616 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Rg88 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Rgb332 => Some(FormatInfo::new(
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Rgb565 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Rgb565_a8 => Some(FormatInfo::new(
+                [2, 1, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Rgb888 => Some(FormatInfo::new(
+                [3, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Rgb888_a8 => Some(FormatInfo::new(
+                [3, 1, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Rgba1010102 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Rgba4444 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Rgba5551 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Rgba8888 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Rgbx1010102 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Rgbx4444 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Rgbx5551 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Rgbx8888 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Rgbx8888_a8 => Some(FormatInfo::new(
+                [4, 1, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Uyvy => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::Vuy101010 => Some(FormatInfo::new(
+                [0, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Vuy888 => Some(FormatInfo::new(
+                [3, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Vyuy => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::X0l0 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [2, 0, 0, 0],
+                [2, 0, 0, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::X0l2 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [2, 0, 0, 0],
+                [2, 0, 0, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::Xbgr1555 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xbgr16161616 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xbgr16161616f => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xbgr2101010 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xbgr4444 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xbgr8888 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xbgr8888_a8 => Some(FormatInfo::new(
+                [4, 1, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Xrgb1555 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xrgb16161616 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xrgb16161616f => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xrgb2101010 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xrgb4444 => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xrgb8888 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                false,
+            )),
+            Self::Xrgb8888_a8 => Some(FormatInfo::new(
+                [4, 1, 0, 0],
+                [1, 1, 0, 0],
+                [1, 1, 0, 0],
+                (1, 1),
+                true,
+                false,
+            )),
+            Self::Xvyu12_16161616 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Xvyu16161616 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Xvyu2101010 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Xyuv8888 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Y0l0 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [2, 0, 0, 0],
+                [2, 0, 0, 0],
+                (2, 2),
+                true,
+                true,
+            )),
+            Self::Y0l2 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [2, 0, 0, 0],
+                [2, 0, 0, 0],
+                (2, 2),
+                true,
+                true,
+            )),
+            Self::Y210 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::Y212 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::Y216 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::Y410 => Some(FormatInfo::new(
+                [4, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                true,
+            )),
+            Self::Y412 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                true,
+            )),
+            Self::Y416 => Some(FormatInfo::new(
+                [8, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (1, 1),
+                true,
+                true,
+            )),
+            Self::Yuv410 => Some(FormatInfo::new(
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (4, 4),
+                false,
+                true,
+            )),
+            Self::Yuv411 => Some(FormatInfo::new(
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (4, 1),
+                false,
+                true,
+            )),
+            Self::Yuv420 => Some(FormatInfo::new(
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::Yuv420_10bit => Some(FormatInfo::new(
+                [0, 0, 0, 0],
+                [4, 0, 0, 0],
+                [4, 0, 0, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::Yuv420_8bit => Some(FormatInfo::new(
+                [0, 0, 0, 0],
+                [4, 0, 0, 0],
+                [4, 0, 0, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::Yuv422 => Some(FormatInfo::new(
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::Yuv444 => Some(FormatInfo::new(
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Yuyv => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::Yvu410 => Some(FormatInfo::new(
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (4, 4),
+                false,
+                true,
+            )),
+            Self::Yvu411 => Some(FormatInfo::new(
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (4, 1),
+                false,
+                true,
+            )),
+            Self::Yvu420 => Some(FormatInfo::new(
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (2, 2),
+                false,
+                true,
+            )),
+            Self::Yvu422 => Some(FormatInfo::new(
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+            Self::Yvu444 => Some(FormatInfo::new(
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                (1, 1),
+                false,
+                true,
+            )),
+            Self::Yvyu => Some(FormatInfo::new(
+                [2, 0, 0, 0],
+                [1, 0, 0, 0],
+                [1, 0, 0, 0],
+                (2, 1),
+                false,
+                true,
+            )),
+        }
+    }
 }
+pub(crate) const FFMPEG_PIX_FMTS: &[(DrmFourcc, &str)] = &[
+    (DrmFourcc::Argb8888, "bgra"),
+    (DrmFourcc::Xrgb8888, "bgr0"),
+    (DrmFourcc::Abgr8888, "rgba"),
+    (DrmFourcc::Xbgr8888, "rgb0"),
+    (DrmFourcc::Rgba8888, "abgr"),
+    (DrmFourcc::Rgbx8888, "0bgr"),
+    (DrmFourcc::Bgra8888, "argb"),
+    (DrmFourcc::Bgrx8888, "0rgb"),
+    (DrmFourcc::Rgb888, "bgr24"),
+    (DrmFourcc::Bgr888, "rgb24"),
+    (DrmFourcc::Rgb565, "rgb565le"),
+    (DrmFourcc::Bgr565, "bgr565le"),
+    (DrmFourcc::Xrgb1555, "rgb555le"),
+    (DrmFourcc::Xbgr1555, "bgr555le"),
+    (DrmFourcc::Xrgb4444, "rgb444le"),
+    (DrmFourcc::Xbgr4444, "bgr444le"),
+    (DrmFourcc::Rgb332, "rgb8"),
+    (DrmFourcc::Bgr233, "bgr8"),
+    (DrmFourcc::Xrgb2101010, "x2rgb10le"),
+    (DrmFourcc::Xbgr2101010, "x2bgr10le"),
+    (DrmFourcc::Abgr16161616, "rgba64le"),
+    (DrmFourcc::Argb16161616, "bgra64le"),
+    (DrmFourcc::R8, "gray"),
+    (DrmFourcc::R16, "gray16le"),
+    (DrmFourcc::Yuyv, "yuyv422"),
+    (DrmFourcc::Yvyu, "yvyu422"),
+    (DrmFourcc::Uyvy, "uyvy422"),
+    (DrmFourcc::Ayuv, "vuya"),
+    (DrmFourcc::Xyuv8888, "vuyx"),
+    (DrmFourcc::Xvyu2101010, "xv30le"),
+    (DrmFourcc::Y210, "y210le"),
+    (DrmFourcc::Y212, "y212le"),
+    (DrmFourcc::Nv12, "nv12"),
+    (DrmFourcc::Nv21, "nv21"),
+    (DrmFourcc::Nv16, "nv16"),
+    (DrmFourcc::Nv24, "nv24"),
+    (DrmFourcc::Nv42, "nv42"),
+    (DrmFourcc::P010, "p010le"),
+    (DrmFourcc::P012, "p012le"),
+    (DrmFourcc::P016, "p016le"),
+    (DrmFourcc::P210, "p210le"),
+    (DrmFourcc::Yuv410, "yuv410p"),
+    (DrmFourcc::Yuv411, "yuv411p"),
+    (DrmFourcc::Yuv420, "yuv420p"),
+    (DrmFourcc::Yuv422, "yuv422p"),
+    (DrmFourcc::Yuv444, "yuv444p"),
+];
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
@@ -246,7 +1397,7 @@ pub enum DrmVendor {
     Vivante = consts::DRM_FOURCC_VIVANTE,
 }
 impl DrmVendor {
-    pub(crate) fn from_u8(n: u8) -> Option<Self> {
+    pub(crate) const fn from_u8(n: u8) -> Option<Self> {
         match n {
             consts::DRM_FOURCC_ALLWINNER => Some(Self::Allwinner),
             consts::DRM_FOURCC_AMD => Some(Self::Amd),
@@ -261,6 +1412,35 @@ impl DrmVendor {
             _ => None,
         }
     }
+    pub(crate) fn names(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Allwinner => ("DRM_FORMAT_MOD_VENDOR_ALLWINNER", "ALLWINNER"),
+            Self::Amd => ("DRM_FORMAT_MOD_VENDOR_AMD", "AMD"),
+            Self::Amlogic => ("DRM_FORMAT_MOD_VENDOR_AMLOGIC", "AMLOGIC"),
+            Self::Arm => ("DRM_FORMAT_MOD_VENDOR_ARM", "ARM"),
+            Self::Broadcom => ("DRM_FORMAT_MOD_VENDOR_BROADCOM", "BROADCOM"),
+            Self::Intel => ("DRM_FORMAT_MOD_VENDOR_INTEL", "INTEL"),
+            Self::Nvidia => ("DRM_FORMAT_MOD_VENDOR_NVIDIA", "NVIDIA"),
+            Self::Qcom => ("DRM_FORMAT_MOD_VENDOR_QCOM", "QCOM"),
+            Self::Samsung => ("DRM_FORMAT_MOD_VENDOR_SAMSUNG", "SAMSUNG"),
+            Self::Vivante => ("DRM_FORMAT_MOD_VENDOR_VIVANTE", "VIVANTE"),
+        }
+    }
+    pub(crate) fn from_any_name(name: &str) -> Option<Self> {
+        match name {
+            "DRM_FORMAT_MOD_VENDOR_ALLWINNER" | "ALLWINNER" => Some(Self::Allwinner),
+            "DRM_FORMAT_MOD_VENDOR_AMD" | "AMD" => Some(Self::Amd),
+            "DRM_FORMAT_MOD_VENDOR_AMLOGIC" | "AMLOGIC" => Some(Self::Amlogic),
+            "DRM_FORMAT_MOD_VENDOR_ARM" | "ARM" => Some(Self::Arm),
+            "DRM_FORMAT_MOD_VENDOR_BROADCOM" | "BROADCOM" => Some(Self::Broadcom),
+            "DRM_FORMAT_MOD_VENDOR_INTEL" | "INTEL" => Some(Self::Intel),
+            "DRM_FORMAT_MOD_VENDOR_NVIDIA" | "NVIDIA" => Some(Self::Nvidia),
+            "DRM_FORMAT_MOD_VENDOR_QCOM" | "QCOM" => Some(Self::Qcom),
+            "DRM_FORMAT_MOD_VENDOR_SAMSUNG" | "SAMSUNG" => Some(Self::Samsung),
+            "DRM_FORMAT_MOD_VENDOR_VIVANTE" | "VIVANTE" => Some(Self::Vivante),
+            _ => None,
+        }
+    }
 }
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -377,4 +1557,152 @@ impl DrmModifier {
             Self::Unrecognized(x) => x,
         }
     }
+    pub(crate) fn names(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::Allwinner_tiled => Some(("DRM_FORMAT_MOD_ALLWINNER_TILED", "ALLWINNER_TILED")),
+            Self::Broadcom_sand128 => Some(("DRM_FORMAT_MOD_BROADCOM_SAND128", "BROADCOM_SAND128")),
+            Self::Broadcom_sand256 => Some(("DRM_FORMAT_MOD_BROADCOM_SAND256", "BROADCOM_SAND256")),
+            Self::Broadcom_sand32 => Some(("DRM_FORMAT_MOD_BROADCOM_SAND32", "BROADCOM_SAND32")),
+            Self::Broadcom_sand64 => Some(("DRM_FORMAT_MOD_BROADCOM_SAND64", "BROADCOM_SAND64")),
+            Self::Broadcom_uif => Some(("DRM_FORMAT_MOD_BROADCOM_UIF", "BROADCOM_UIF")),
+            Self::Broadcom_vc4_t_tiled => Some((
+                "DRM_FORMAT_MOD_BROADCOM_VC4_T_TILED",
+                "BROADCOM_VC4_T_TILED",
+            )),
+            Self::Generic_16_16_tile => {
+                Some(("DRM_FORMAT_MOD_GENERIC_16_16_TILE", "GENERIC_16_16_TILE"))
+            }
+            Self::Invalid => Some(("DRM_FORMAT_MOD_INVALID", "INVALID")),
+            Self::Linear => Some(("DRM_FORMAT_MOD_LINEAR", "LINEAR")),
+            Self::Nvidia_16bx2_block_eight_gob => Some((
+                "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_EIGHT_GOB",
+                "NVIDIA_16BX2_BLOCK_EIGHT_GOB",
+            )),
+            Self::Nvidia_16bx2_block_four_gob => Some((
+                "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_FOUR_GOB",
+                "NVIDIA_16BX2_BLOCK_FOUR_GOB",
+            )),
+            Self::Nvidia_16bx2_block_one_gob => Some((
+                "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_ONE_GOB",
+                "NVIDIA_16BX2_BLOCK_ONE_GOB",
+            )),
+            Self::Nvidia_16bx2_block_sixteen_gob => Some((
+                "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_SIXTEEN_GOB",
+                "NVIDIA_16BX2_BLOCK_SIXTEEN_GOB",
+            )),
+            Self::Nvidia_16bx2_block_thirtytwo_gob => Some((
+                "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_THIRTYTWO_GOB",
+                "NVIDIA_16BX2_BLOCK_THIRTYTWO_GOB",
+            )),
+            Self::Nvidia_16bx2_block_two_gob => Some((
+                "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_TWO_GOB",
+                "NVIDIA_16BX2_BLOCK_TWO_GOB",
+            )),
+            Self::Nvidia_tegra_tiled => {
+                Some(("DRM_FORMAT_MOD_NVIDIA_TEGRA_TILED", "NVIDIA_TEGRA_TILED"))
+            }
+            Self::Qcom_compressed => Some(("DRM_FORMAT_MOD_QCOM_COMPRESSED", "QCOM_COMPRESSED")),
+            Self::Samsung_16_16_tile => {
+                Some(("DRM_FORMAT_MOD_SAMSUNG_16_16_TILE", "SAMSUNG_16_16_TILE"))
+            }
+            Self::Samsung_64_32_tile => {
+                Some(("DRM_FORMAT_MOD_SAMSUNG_64_32_TILE", "SAMSUNG_64_32_TILE"))
+            }
+            Self::Vivante_split_super_tiled => Some((
+                "DRM_FORMAT_MOD_VIVANTE_SPLIT_SUPER_TILED",
+                "VIVANTE_SPLIT_SUPER_TILED",
+            )),
+            Self::Vivante_split_tiled => {
+                Some(("DRM_FORMAT_MOD_VIVANTE_SPLIT_TILED", "VIVANTE_SPLIT_TILED"))
+            }
+            Self::Vivante_super_tiled => {
+                Some(("DRM_FORMAT_MOD_VIVANTE_SUPER_TILED", "VIVANTE_SUPER_TILED"))
+            }
+            Self::Vivante_tiled => Some(("DRM_FORMAT_MOD_VIVANTE_TILED", "VIVANTE_TILED")),
+            Self::I915_x_tiled => Some(("I915_FORMAT_MOD_X_TILED", "I915_X_TILED")),
+            Self::I915_y_tiled => Some(("I915_FORMAT_MOD_Y_TILED", "I915_Y_TILED")),
+            Self::I915_y_tiled_ccs => Some(("I915_FORMAT_MOD_Y_TILED_CCS", "I915_Y_TILED_CCS")),
+            Self::I915_y_tiled_gen12_mc_ccs => Some((
+                "I915_FORMAT_MOD_Y_TILED_GEN12_MC_CCS",
+                "I915_Y_TILED_GEN12_MC_CCS",
+            )),
+            Self::I915_y_tiled_gen12_rc_ccs => Some((
+                "I915_FORMAT_MOD_Y_TILED_GEN12_RC_CCS",
+                "I915_Y_TILED_GEN12_RC_CCS",
+            )),
+            Self::I915_y_tiled_gen12_rc_ccs_cc => Some((
+                "I915_FORMAT_MOD_Y_TILED_GEN12_RC_CCS_CC",
+                "I915_Y_TILED_GEN12_RC_CCS_CC",
+            )),
+            Self::Unrecognized(_) => None,
+        }
+    }
+    pub(crate) fn from_any_name(name: &str) -> Option<Self> {
+        match name {
+            "DRM_FORMAT_MOD_ALLWINNER_TILED" | "ALLWINNER_TILED" => Some(Self::Allwinner_tiled),
+            "DRM_FORMAT_MOD_BROADCOM_SAND128" | "BROADCOM_SAND128" => Some(Self::Broadcom_sand128),
+            "DRM_FORMAT_MOD_BROADCOM_SAND256" | "BROADCOM_SAND256" => Some(Self::Broadcom_sand256),
+            "DRM_FORMAT_MOD_BROADCOM_SAND32" | "BROADCOM_SAND32" => Some(Self::Broadcom_sand32),
+            "DRM_FORMAT_MOD_BROADCOM_SAND64" | "BROADCOM_SAND64" => Some(Self::Broadcom_sand64),
+            "DRM_FORMAT_MOD_BROADCOM_UIF" | "BROADCOM_UIF" => Some(Self::Broadcom_uif),
+            "DRM_FORMAT_MOD_BROADCOM_VC4_T_TILED" | "BROADCOM_VC4_T_TILED" => {
+                Some(Self::Broadcom_vc4_t_tiled)
+            }
+            "DRM_FORMAT_MOD_GENERIC_16_16_TILE" | "GENERIC_16_16_TILE" => {
+                Some(Self::Generic_16_16_tile)
+            }
+            "DRM_FORMAT_MOD_INVALID" | "INVALID" => Some(Self::Invalid),
+            "DRM_FORMAT_MOD_LINEAR" | "LINEAR" => Some(Self::Linear),
+            "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_EIGHT_GOB" | "NVIDIA_16BX2_BLOCK_EIGHT_GOB" => {
+                Some(Self::Nvidia_16bx2_block_eight_gob)
+            }
+            "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_FOUR_GOB" | "NVIDIA_16BX2_BLOCK_FOUR_GOB" => {
+                Some(Self::Nvidia_16bx2_block_four_gob)
+            }
+            "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_ONE_GOB" | "NVIDIA_16BX2_BLOCK_ONE_GOB" => {
+                Some(Self::Nvidia_16bx2_block_one_gob)
+            }
+            "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_SIXTEEN_GOB" | "NVIDIA_16BX2_BLOCK_SIXTEEN_GOB" => {
+                Some(Self::Nvidia_16bx2_block_sixteen_gob)
+            }
+            "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_THIRTYTWO_GOB"
+            | "NVIDIA_16BX2_BLOCK_THIRTYTWO_GOB" => Some(Self::Nvidia_16bx2_block_thirtytwo_gob),
+            "DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK_TWO_GOB" | "NVIDIA_16BX2_BLOCK_TWO_GOB" => {
+                Some(Self::Nvidia_16bx2_block_two_gob)
+            }
+            "DRM_FORMAT_MOD_NVIDIA_TEGRA_TILED" | "NVIDIA_TEGRA_TILED" => {
+                Some(Self::Nvidia_tegra_tiled)
+            }
+            "DRM_FORMAT_MOD_QCOM_COMPRESSED" | "QCOM_COMPRESSED" => Some(Self::Qcom_compressed),
+            "DRM_FORMAT_MOD_SAMSUNG_16_16_TILE" | "SAMSUNG_16_16_TILE" => {
+                Some(Self::Samsung_16_16_tile)
+            }
+            "DRM_FORMAT_MOD_SAMSUNG_64_32_TILE" | "SAMSUNG_64_32_TILE" => {
+                Some(Self::Samsung_64_32_tile)
+            }
+            "DRM_FORMAT_MOD_VIVANTE_SPLIT_SUPER_TILED" | "VIVANTE_SPLIT_SUPER_TILED" => {
+                Some(Self::Vivante_split_super_tiled)
+            }
+            "DRM_FORMAT_MOD_VIVANTE_SPLIT_TILED" | "VIVANTE_SPLIT_TILED" => {
+                Some(Self::Vivante_split_tiled)
+            }
+            "DRM_FORMAT_MOD_VIVANTE_SUPER_TILED" | "VIVANTE_SUPER_TILED" => {
+                Some(Self::Vivante_super_tiled)
+            }
+            "DRM_FORMAT_MOD_VIVANTE_TILED" | "VIVANTE_TILED" => Some(Self::Vivante_tiled),
+            "I915_FORMAT_MOD_X_TILED" | "I915_X_TILED" => Some(Self::I915_x_tiled),
+            "I915_FORMAT_MOD_Y_TILED" | "I915_Y_TILED" => Some(Self::I915_y_tiled),
+            "I915_FORMAT_MOD_Y_TILED_CCS" | "I915_Y_TILED_CCS" => Some(Self::I915_y_tiled_ccs),
+            "I915_FORMAT_MOD_Y_TILED_GEN12_MC_CCS" | "I915_Y_TILED_GEN12_MC_CCS" => {
+                Some(Self::I915_y_tiled_gen12_mc_ccs)
+            }
+            "I915_FORMAT_MOD_Y_TILED_GEN12_RC_CCS" | "I915_Y_TILED_GEN12_RC_CCS" => {
+                Some(Self::I915_y_tiled_gen12_rc_ccs)
+            }
+            "I915_FORMAT_MOD_Y_TILED_GEN12_RC_CCS_CC" | "I915_Y_TILED_GEN12_RC_CCS_CC" => {
+                Some(Self::I915_y_tiled_gen12_rc_ccs_cc)
+            }
+            _ => None,
+        }
+    }
 }
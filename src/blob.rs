@@ -0,0 +1,345 @@
+//! Parsing of the `IN_FORMATS` plane property, see `struct drm_format_modifier_blob` in
+//! `drm_mode.h`.
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::{DrmFormat, DrmFourcc, DrmModifier};
+
+/// The only version of the blob layout the kernel has defined (`FORMAT_BLOB_CURRENT`)
+const BLOB_VERSION: u32 = 1;
+/// Size of `struct drm_format_modifier_blob`
+const HEADER_SIZE: usize = 24;
+/// Size of `struct drm_format_modifier`
+const MODIFIER_SIZE: usize = 24;
+
+/// A validated `IN_FORMATS` blob, listing every format and modifier pair a plane supports.
+///
+/// All data is read in native endianness, as it comes straight from the kernel.
+///
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// # use drm_fourcc::{DrmFormat, DrmFourcc, DrmModifier, FormatModifierBlob};
+/// # fn get_blob() -> Vec<u8> {
+/// #     FormatModifierBlob::serialize(&[DrmFormat {
+/// #         code: DrmFourcc::Xrgb8888,
+/// #         modifier: DrmModifier::Linear,
+/// #     }])
+/// # }
+/// let data = get_blob();
+/// let blob = FormatModifierBlob::parse(&data).unwrap();
+///
+/// for format in blob.iter() {
+///     println!("{} {}", format.code, format.modifier);
+/// }
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct FormatModifierBlob<'a> {
+    formats: &'a [u8],
+    modifiers: &'a [u8],
+}
+
+impl<'a> FormatModifierBlob<'a> {
+    /// Validate the header and every modifier entry of a blob
+    pub fn parse(data: &'a [u8]) -> Result<Self, InvalidBlob> {
+        if data.len() < HEADER_SIZE {
+            return Err(InvalidBlob::TooShort);
+        }
+
+        let version = read_u32(data, 0);
+        if version != BLOB_VERSION {
+            return Err(InvalidBlob::UnsupportedVersion(version));
+        }
+
+        let formats = section(data, read_u32(data, 8), read_u32(data, 12), 4)
+            .ok_or(InvalidBlob::FormatsOutOfBounds)?;
+        let modifiers = section(data, read_u32(data, 16), read_u32(data, 20), MODIFIER_SIZE)
+            .ok_or(InvalidBlob::ModifiersOutOfBounds)?;
+
+        let count_formats = formats.len() / 4;
+        for entry in modifiers.chunks_exact(MODIFIER_SIZE) {
+            let mask = read_u64(entry, 0);
+            let offset = read_u32(entry, 8) as usize;
+            let end = offset
+                .checked_add(64 - mask.leading_zeros() as usize)
+                .ok_or(InvalidBlob::FormatIndexOutOfBounds)?;
+            if mask != 0 && end > count_formats {
+                return Err(InvalidBlob::FormatIndexOutOfBounds);
+            }
+        }
+
+        Ok(FormatModifierBlob { formats, modifiers })
+    }
+
+    /// Iterate over every supported format and modifier pair
+    ///
+    /// Format codes this crate doesn't recognize are skipped.
+    pub fn iter(&self) -> FormatModifierBlobIter<'a> {
+        FormatModifierBlobIter {
+            formats: self.formats,
+            modifiers: self.modifiers,
+            bit: 0,
+        }
+    }
+
+    /// Build a blob in the layout the kernel uses, for example as a test fixture
+    ///
+    /// Formats and modifiers are sorted and deduplicated, so parsing the blob yields the pairs
+    /// ordered by modifier, then by format code.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFormat, DrmFourcc, DrmModifier, FormatModifierBlob};
+    /// let formats = [
+    ///     DrmFormat { code: DrmFourcc::Xrgb8888, modifier: DrmModifier::Linear },
+    ///     DrmFormat { code: DrmFourcc::Nv12, modifier: DrmModifier::I915_y_tiled },
+    /// ];
+    /// let data = FormatModifierBlob::serialize(&formats);
+    ///
+    /// let parsed: Vec<_> = FormatModifierBlob::parse(&data).unwrap().iter().collect();
+    /// assert_eq!(parsed, formats[..]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn serialize<'f, I>(formats: I) -> Vec<u8>
+    where
+        I: IntoIterator<Item = &'f DrmFormat>,
+    {
+        let pairs: Vec<DrmFormat> = formats.into_iter().copied().collect();
+
+        let mut codes: Vec<u32> = pairs.iter().map(|format| format.code as u32).collect();
+        codes.sort_unstable();
+        codes.dedup();
+        let mut modifiers: Vec<u64> = pairs.iter().map(|format| format.modifier.into()).collect();
+        modifiers.sort_unstable();
+        modifiers.dedup();
+
+        let formats_offset = HEADER_SIZE;
+        let modifiers_offset = (formats_offset + codes.len() * 4 + 7) & !7;
+
+        // One entry for every window of 64 formats a modifier is used with.
+        let mut entries = Vec::new();
+        for &modifier in &modifiers {
+            for (window, chunk) in codes.chunks(64).enumerate() {
+                let mask = chunk
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &code)| {
+                        pairs
+                            .iter()
+                            .any(|format| format.code as u32 == code && format.modifier == modifier)
+                    })
+                    .fold(0u64, |mask, (bit, _)| mask | 1 << bit);
+
+                if mask != 0 {
+                    entries.push((mask, window as u32 * 64, modifier));
+                }
+            }
+        }
+
+        let mut data = Vec::with_capacity(modifiers_offset + entries.len() * MODIFIER_SIZE);
+        for field in [
+            BLOB_VERSION,
+            0,
+            codes.len() as u32,
+            formats_offset as u32,
+            entries.len() as u32,
+            modifiers_offset as u32,
+        ]
+        .iter()
+        {
+            data.extend_from_slice(&field.to_ne_bytes());
+        }
+        for code in &codes {
+            data.extend_from_slice(&code.to_ne_bytes());
+        }
+        data.resize(modifiers_offset, 0);
+        for (mask, offset, modifier) in entries {
+            data.extend_from_slice(&mask.to_ne_bytes());
+            data.extend_from_slice(&offset.to_ne_bytes());
+            data.extend_from_slice(&0u32.to_ne_bytes());
+            data.extend_from_slice(&modifier.to_ne_bytes());
+        }
+
+        data
+    }
+}
+
+impl<'a> IntoIterator for &FormatModifierBlob<'a> {
+    type Item = DrmFormat;
+    type IntoIter = FormatModifierBlobIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the format and modifier pairs of a [`FormatModifierBlob`]
+#[derive(Debug, Clone)]
+pub struct FormatModifierBlobIter<'a> {
+    formats: &'a [u8],
+    /// Modifier entries that haven't been fully visited yet
+    modifiers: &'a [u8],
+    /// Next bit of the current entry's mask to look at
+    bit: u32,
+}
+
+impl Iterator for FormatModifierBlobIter<'_> {
+    type Item = DrmFormat;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.modifiers.len() >= MODIFIER_SIZE {
+            let mask = read_u64(self.modifiers, 0);
+            let offset = read_u32(self.modifiers, 8) as usize;
+            let modifier = read_u64(self.modifiers, 16);
+
+            let remaining = mask.checked_shr(self.bit).unwrap_or(0);
+            if remaining == 0 {
+                self.modifiers = &self.modifiers[MODIFIER_SIZE..];
+                self.bit = 0;
+                continue;
+            }
+
+            let bit = self.bit + remaining.trailing_zeros();
+            self.bit = bit + 1;
+
+            let code = read_u32(self.formats, (offset + bit as usize) * 4);
+            if let Ok(code) = DrmFourcc::try_from(code) {
+                return Some(DrmFormat {
+                    code,
+                    modifier: DrmModifier::from(modifier),
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Get `count` items of `size` bytes at `offset`, if they are within `data`.
+fn section(data: &[u8], count: u32, offset: u32, size: usize) -> Option<&[u8]> {
+    let start = offset as usize;
+    let end = (count as usize).checked_mul(size)?.checked_add(start)?;
+    data.get(start..end)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(&data[offset..offset + 4]);
+    u32::from_ne_bytes(bytes)
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_ne_bytes(bytes)
+}
+
+/// Reasons an `IN_FORMATS` blob can't be parsed
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InvalidBlob {
+    /// The blob is shorter than its header
+    TooShort,
+    /// The header has a version other than 1
+    UnsupportedVersion(u32),
+    /// The format list extends beyond the end of the blob
+    FormatsOutOfBounds,
+    /// The modifier list extends beyond the end of the blob
+    ModifiersOutOfBounds,
+    /// A modifier refers to a format past the end of the format list
+    FormatIndexOutOfBounds,
+}
+
+impl Display for InvalidBlob {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidBlob {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn format(code: DrmFourcc, modifier: DrmModifier) -> DrmFormat {
+        DrmFormat { code, modifier }
+    }
+
+    #[test]
+    fn round_trips_shared_modifiers() {
+        let formats = [
+            format(DrmFourcc::Argb8888, DrmModifier::Linear),
+            format(DrmFourcc::Argb8888, DrmModifier::I915_x_tiled),
+            format(DrmFourcc::Xrgb8888, DrmModifier::Linear),
+            format(DrmFourcc::Xrgb8888, DrmModifier::I915_x_tiled),
+            format(DrmFourcc::Nv12, DrmModifier::I915_y_tiled),
+        ];
+        let data = FormatModifierBlob::serialize(&formats);
+        let blob = FormatModifierBlob::parse(&data).unwrap();
+
+        let mut parsed: Vec<_> = blob.iter().collect();
+        parsed.sort_by_key(|format| (format.code as u32, u64::from(format.modifier)));
+        let mut expected = formats.to_vec();
+        expected.sort_by_key(|format| (format.code as u32, u64::from(format.modifier)));
+
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn skips_unrecognized_formats() {
+        let mut data = FormatModifierBlob::serialize(&[
+            format(DrmFourcc::Xrgb8888, DrmModifier::Linear),
+            format(DrmFourcc::Argb8888, DrmModifier::Linear),
+        ]);
+        // Argb8888 sorts first, replace it with "avc1".
+        data[HEADER_SIZE..HEADER_SIZE + 4].copy_from_slice(&828601953u32.to_ne_bytes());
+
+        let blob = FormatModifierBlob::parse(&data).unwrap();
+        assert_eq!(
+            blob.iter().collect::<Vec<_>>(),
+            [format(DrmFourcc::Xrgb8888, DrmModifier::Linear)]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_blobs() {
+        let data =
+            FormatModifierBlob::serialize(&[format(DrmFourcc::Xrgb8888, DrmModifier::Linear)]);
+
+        assert_eq!(
+            FormatModifierBlob::parse(&data[..HEADER_SIZE - 1]).unwrap_err(),
+            InvalidBlob::TooShort
+        );
+        assert_eq!(
+            FormatModifierBlob::parse(&data[..data.len() - 1]).unwrap_err(),
+            InvalidBlob::ModifiersOutOfBounds
+        );
+
+        let mut bad_version = data.clone();
+        bad_version[..4].copy_from_slice(&2u32.to_ne_bytes());
+        assert_eq!(
+            FormatModifierBlob::parse(&bad_version).unwrap_err(),
+            InvalidBlob::UnsupportedVersion(2)
+        );
+
+        let mut bad_mask = data.clone();
+        let entry = bad_mask.len() - MODIFIER_SIZE;
+        bad_mask[entry..entry + 8].copy_from_slice(&0b10u64.to_ne_bytes());
+        assert_eq!(
+            FormatModifierBlob::parse(&bad_mask).unwrap_err(),
+            InvalidBlob::FormatIndexOutOfBounds
+        );
+
+        let mut bad_offset = data;
+        bad_offset[entry + 8..entry + 12].copy_from_slice(&u32::MAX.to_ne_bytes());
+        assert_eq!(
+            FormatModifierBlob::parse(&bad_offset).unwrap_err(),
+            InvalidBlob::FormatIndexOutOfBounds
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Decoding of the parameterized Broadcom SAND modifiers, see
+//! `DRM_FORMAT_MOD_BROADCOM_SAND32_COL_HEIGHT` in `drm_fourcc.h`.
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+use crate::{DrmModifier, DrmVendor};
+
+const VENDOR_SHIFT: u32 = 56;
+const PARAM_SHIFT: u32 = 8;
+const PARAM_MASK: u64 = (1 << 48) - 1;
+const CODE_MASK: u64 = 0xff;
+
+/// Width of a SAND column in bytes
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BroadcomSandWidth {
+    Sand32,
+    Sand64,
+    Sand128,
+    Sand256,
+}
+
+impl BroadcomSandWidth {
+    /// Width of a column in bytes
+    pub fn bytes(&self) -> u32 {
+        match self {
+            Self::Sand32 => 32,
+            Self::Sand64 => 64,
+            Self::Sand128 => 128,
+            Self::Sand256 => 256,
+        }
+    }
+
+    fn from_code(code: u64) -> Option<Self> {
+        match code {
+            2 => Some(Self::Sand32),
+            3 => Some(Self::Sand64),
+            4 => Some(Self::Sand128),
+            5 => Some(Self::Sand256),
+            _ => None,
+        }
+    }
+
+    fn code(&self) -> u64 {
+        match self {
+            Self::Sand32 => 2,
+            Self::Sand64 => 3,
+            Self::Sand128 => 4,
+            Self::Sand256 => 5,
+        }
+    }
+}
+
+/// The parameters of a Broadcom SAND modifier.
+///
+/// SAND buffers are split into vertical columns `column_width` bytes wide, each stored
+/// contiguously as `column_height` lines. For multi-planar formats the chroma lines of a column
+/// follow its luma lines.
+///
+/// A `column_height` of 0 is what the unparameterized `Broadcom_sand*` modifiers encode; the
+/// column height is then given by the framebuffer pitch instead.
+///
+/// ```
+/// # use drm_fourcc::{BroadcomSand, BroadcomSandWidth, DrmModifier};
+/// let sand = BroadcomSand {
+///     column_width: BroadcomSandWidth::Sand128,
+///     column_height: 1632,
+/// };
+/// let modifier = DrmModifier::from(sand);
+///
+/// assert_eq!(modifier.broadcom_sand(), Some(sand));
+/// assert_eq!(
+///     DrmModifier::Broadcom_sand128.broadcom_sand().unwrap().column_height,
+///     0
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BroadcomSand {
+    /// Width of a column
+    pub column_width: BroadcomSandWidth,
+    /// Number of lines in a column
+    pub column_height: u32,
+}
+
+impl BroadcomSand {
+    /// Decode a SAND modifier from its u64 form.
+    ///
+    /// Returns `None` if this isn't a Broadcom SAND modifier, or the column height doesn't fit in
+    /// an u32.
+    pub fn from_u64(value: u64) -> Option<Self> {
+        if (value >> VENDOR_SHIFT) as u8 != DrmVendor::Broadcom as u8 {
+            return None;
+        }
+
+        Some(BroadcomSand {
+            column_width: BroadcomSandWidth::from_code(value & CODE_MASK)?,
+            column_height: u32::try_from((value >> PARAM_SHIFT) & PARAM_MASK).ok()?,
+        })
+    }
+
+    /// Encode the modifier to its u64 form.
+    pub fn into_u64(self) -> u64 {
+        (u64::from(DrmVendor::Broadcom as u8) << VENDOR_SHIFT)
+            | (u64::from(self.column_height) << PARAM_SHIFT)
+            | self.column_width.code()
+    }
+
+    /// Number of bytes between the start of two consecutive columns.
+    pub fn column_stride(&self) -> u64 {
+        u64::from(self.column_width.bytes()) * u64::from(self.column_height)
+    }
+
+    /// Byte offset of byte `x` of line `y`, counting lines from the start of the column.
+    ///
+    /// The chroma plane of a SAND buffer starts at `byte_offset(0, luma_height)`.
+    ///
+    /// ```
+    /// # use drm_fourcc::{BroadcomSand, BroadcomSandWidth};
+    /// let sand = BroadcomSand {
+    ///     column_width: BroadcomSandWidth::Sand128,
+    ///     column_height: 96,
+    /// };
+    ///
+    /// assert_eq!(sand.byte_offset(0, 64), 64 * 128);
+    /// assert_eq!(sand.byte_offset(130, 1), 96 * 128 + 128 + 2);
+    /// ```
+    pub fn byte_offset(&self, x: u32, y: u32) -> u64 {
+        let width = self.column_width.bytes();
+
+        u64::from(x / width) * self.column_stride()
+            + u64::from(y) * u64::from(width)
+            + u64::from(x % width)
+    }
+}
+
+impl Display for BroadcomSand {
+    /// Format as the `DRM_FORMAT_MOD_BROADCOM_SAND*_COL_HEIGHT` macro producing the modifier
+    ///
+    /// ```
+    /// # use drm_fourcc::{BroadcomSand, BroadcomSandWidth};
+    /// let sand = BroadcomSand {
+    ///     column_width: BroadcomSandWidth::Sand128,
+    ///     column_height: 96,
+    /// };
+    /// assert_eq!(sand.to_string(), "BROADCOM_SAND128_COL_HEIGHT(96)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "BROADCOM_SAND{}_COL_HEIGHT({})",
+            self.column_width.bytes(),
+            self.column_height
+        )
+    }
+}
+
+impl From<BroadcomSand> for u64 {
+    fn from(val: BroadcomSand) -> u64 {
+        val.into_u64()
+    }
+}
+
+impl From<BroadcomSand> for DrmModifier {
+    fn from(val: BroadcomSand) -> DrmModifier {
+        DrmModifier::from(val.into_u64())
+    }
+}
+
+impl DrmModifier {
+    /// Decode the parameters of a Broadcom SAND modifier
+    ///
+    /// ```
+    /// # use drm_fourcc::{BroadcomSandWidth, DrmModifier};
+    /// let sand = DrmModifier::from(0x0700_0000_0006_6003).broadcom_sand().unwrap();
+    /// assert_eq!(sand.column_width, BroadcomSandWidth::Sand64);
+    /// assert_eq!(sand.column_height, 0x660);
+    ///
+    /// assert!(DrmModifier::Broadcom_uif.broadcom_sand().is_none());
+    /// ```
+    pub fn broadcom_sand(&self) -> Option<BroadcomSand> {
+        BroadcomSand::from_u64(self.into_u64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unparameterized_constants_decode_with_zero_height() {
+        let constants = [
+            (DrmModifier::Broadcom_sand32, BroadcomSandWidth::Sand32),
+            (DrmModifier::Broadcom_sand64, BroadcomSandWidth::Sand64),
+            (DrmModifier::Broadcom_sand128, BroadcomSandWidth::Sand128),
+            (DrmModifier::Broadcom_sand256, BroadcomSandWidth::Sand256),
+        ];
+
+        for &(modifier, column_width) in constants.iter() {
+            let sand = BroadcomSand {
+                column_width,
+                column_height: 0,
+            };
+            assert_eq!(modifier.broadcom_sand(), Some(sand));
+            assert_eq!(DrmModifier::from(sand), modifier);
+        }
+    }
+
+    #[test]
+    fn rejects_oversized_height() {
+        assert_eq!(BroadcomSand::from_u64(0x0700_0100_0000_0004), None);
+    }
+}
@@ -0,0 +1,33 @@
+//! Mapping to Cairo image surface formats (`cairo_format_t`).
+//!
+//! Cairo's formats are a subset of pixman's, so they are found through
+//! [`DrmFourcc::pixman_format_code`].
+use crate::pixman::{PIXMAN_A8R8G8B8, PIXMAN_R5G6B5, PIXMAN_X2R10G10B10, PIXMAN_X8R8G8B8};
+use crate::DrmFourcc;
+
+const CAIRO_FORMAT_ARGB32: i32 = 0;
+const CAIRO_FORMAT_RGB24: i32 = 1;
+const CAIRO_FORMAT_RGB16_565: i32 = 4;
+const CAIRO_FORMAT_RGB30: i32 = 5;
+
+impl DrmFourcc {
+    /// Get the Cairo format with the same layout
+    ///
+    /// Note that Cairo expects `CAIRO_FORMAT_ARGB32` to be premultiplied.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// # const CAIRO_FORMAT_RGB24: i32 = 1;
+    /// assert_eq!(DrmFourcc::Xrgb8888.cairo_format(), Some(CAIRO_FORMAT_RGB24));
+    /// assert_eq!(DrmFourcc::Abgr8888.cairo_format(), None);
+    /// ```
+    pub fn cairo_format(&self) -> Option<i32> {
+        match self.pixman_format_code()? {
+            PIXMAN_A8R8G8B8 => Some(CAIRO_FORMAT_ARGB32),
+            PIXMAN_X8R8G8B8 => Some(CAIRO_FORMAT_RGB24),
+            PIXMAN_R5G6B5 => Some(CAIRO_FORMAT_RGB16_565),
+            PIXMAN_X2R10G10B10 => Some(CAIRO_FORMAT_RGB30),
+            _ => None,
+        }
+    }
+}
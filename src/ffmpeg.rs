@@ -0,0 +1,54 @@
+//! Names of the FFmpeg pixel formats (`AVPixelFormat`) matching DRM formats, as accepted by
+//! `av_get_pix_fmt` and filter graph options.
+//!
+//! The table lives in `ffmpeg_pix_fmts.txt`, from which `build.rs` generates `FFMPEG_PIX_FMTS` in
+//! `as_enum.rs`.
+use crate::as_enum::FFMPEG_PIX_FMTS;
+use crate::DrmFourcc;
+
+impl DrmFourcc {
+    /// Get the name of the FFmpeg pixel format with the same layout
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// assert_eq!(DrmFourcc::Xrgb8888.ffmpeg_pix_fmt_name(), Some("bgr0"));
+    /// assert_eq!(DrmFourcc::P010.ffmpeg_pix_fmt_name(), Some("p010le"));
+    /// assert_eq!(DrmFourcc::Yvu420.ffmpeg_pix_fmt_name(), None);
+    /// ```
+    pub fn ffmpeg_pix_fmt_name(&self) -> Option<&'static str> {
+        FFMPEG_PIX_FMTS
+            .iter()
+            .find(|(code, _)| code == self)
+            .map(|&(_, name)| name)
+    }
+
+    /// Look up a format by the name of its FFmpeg pixel format
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// assert_eq!(
+    ///     DrmFourcc::from_ffmpeg_pix_fmt_name("x2rgb10le"),
+    ///     Some(DrmFourcc::Xrgb2101010)
+    /// );
+    /// assert_eq!(DrmFourcc::from_ffmpeg_pix_fmt_name("vaapi"), None);
+    /// ```
+    pub fn from_ffmpeg_pix_fmt_name(name: &str) -> Option<Self> {
+        FFMPEG_PIX_FMTS
+            .iter()
+            .find(|&&(_, ffmpeg_name)| ffmpeg_name == name)
+            .map(|&(code, _)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_unique() {
+        for &(code, name) in FFMPEG_PIX_FMTS {
+            assert_eq!(code.ffmpeg_pix_fmt_name(), Some(name));
+            assert_eq!(DrmFourcc::from_ffmpeg_pix_fmt_name(name), Some(code));
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Plane and block layout metadata for every [`DrmFourcc`].
+//!
+//! The table mirrors `drm_format_info` in the kernel's `drivers/gpu/drm/drm_fourcc.c`. It lives
+//! in `format_info.txt`, from which `build.rs` generates the match in `as_enum.rs` along with the
+//! enum itself, and refuses to regenerate if a format has no entry there.
+use crate::DrmFourcc;
+
+/// Layout information about a pixel format, equivalent to the kernel's `struct drm_format_info`.
+///
+/// Per-plane arrays have one entry per plane, entries at or beyond [`FormatInfo::num_planes`] are
+/// zero.
+///
+/// ```
+/// # use drm_fourcc::DrmFourcc;
+/// let info = DrmFourcc::Nv12.info().unwrap();
+/// assert_eq!(info.num_planes, 2);
+/// assert_eq!(info.char_per_block, [1, 2, 0, 0]);
+/// assert_eq!((info.hsub, info.vsub), (2, 2));
+/// assert!(info.is_yuv);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormatInfo {
+    /// Number of color planes (1 to 4)
+    pub num_planes: u8,
+    /// Number of bytes per block, per plane. For formats without blocks this is the number of bytes
+    /// per pixel.
+    ///
+    /// A value of zero means the format can only be used with a compressing modifier (for example
+    /// [`DrmFourcc::Yuv420_8bit`]) and has no meaningful linear layout.
+    pub char_per_block: [u8; 4],
+    /// Width of a block in pixels, per plane
+    pub block_width: [u8; 4],
+    /// Height of a block in pixels, per plane
+    pub block_height: [u8; 4],
+    /// Horizontal chroma subsampling factor
+    pub hsub: u8,
+    /// Vertical chroma subsampling factor
+    pub vsub: u8,
+    /// Does the format embed an alpha component?
+    pub has_alpha: bool,
+    /// Is it a YUV format?
+    pub is_yuv: bool,
+}
+
+impl FormatInfo {
+    /// Used by the table generated from `format_info.txt`. The number of planes is the number of
+    /// non-zero block widths.
+    pub(crate) const fn new(
+        char_per_block: [u8; 4],
+        block_width: [u8; 4],
+        block_height: [u8; 4],
+        (hsub, vsub): (u8, u8),
+        has_alpha: bool,
+        is_yuv: bool,
+    ) -> Self {
+        let mut num_planes = 0;
+        while num_planes < 4 && block_width[num_planes] != 0 {
+            num_planes += 1;
+        }
+
+        FormatInfo {
+            num_planes: num_planes as u8,
+            char_per_block,
+            block_width,
+            block_height,
+            hsub,
+            vsub,
+            has_alpha,
+            is_yuv,
+        }
+    }
+
+    /// Width in pixels of a block in the given plane.
+    ///
+    /// Returns 0 for planes the format does not have.
+    pub fn block_width(&self, plane: usize) -> u32 {
+        self.block_width.get(plane).copied().unwrap_or(0).into()
+    }
+
+    /// Height in pixels of a block in the given plane.
+    ///
+    /// Returns 0 for planes the format does not have.
+    pub fn block_height(&self, plane: usize) -> u32 {
+        self.block_height.get(plane).copied().unwrap_or(0).into()
+    }
+
+    /// Width of the given plane for a buffer `width` pixels wide, taking chroma subsampling into
+    /// account.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// let info = DrmFourcc::Yuv410.info().unwrap();
+    /// assert_eq!(info.plane_width(0, 17), 17);
+    /// assert_eq!(info.plane_width(1, 17), 5);
+    /// ```
+    pub fn plane_width(&self, plane: usize, width: u32) -> u32 {
+        if plane == 0 {
+            width
+        } else {
+            width.div_ceil(self.hsub.into())
+        }
+    }
+
+    /// Height of the given plane for a buffer `height` pixels tall, taking chroma subsampling into
+    /// account.
+    pub fn plane_height(&self, plane: usize, height: u32) -> u32 {
+        if plane == 0 {
+            height
+        } else {
+            height.div_ceil(self.vsub.into())
+        }
+    }
+
+    /// Minimum number of bytes per line of the given plane for a buffer `width` pixels wide.
+    ///
+    /// Equivalent to the kernel's `drm_format_info_min_pitch`, except that `width` is the width of
+    /// the whole buffer rather than of the plane.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// let info = DrmFourcc::Xrgb8888.info().unwrap();
+    /// assert_eq!(info.min_pitch(0, 1920), 7680);
+    /// ```
+    pub fn min_pitch(&self, plane: usize, width: u32) -> u64 {
+        if plane >= self.num_planes.into() {
+            return 0;
+        }
+
+        let width = u64::from(self.plane_width(plane, width));
+        let block_size = u64::from(self.block_width(plane) * self.block_height(plane));
+        let bytes = width * u64::from(self.char_per_block[plane]);
+
+        bytes.div_ceil(block_size)
+    }
+}
+
+impl DrmFourcc {
+    /// Get the plane and block layout of the format.
+    ///
+    /// Returns `None` for [`DrmFourcc::Big_endian`], which is a flag rather than a pixel format.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// let info = DrmFourcc::Argb8888.info().unwrap();
+    /// assert_eq!(info.num_planes, 1);
+    /// assert_eq!(info.char_per_block[0], 4);
+    /// assert!(info.has_alpha);
+    ///
+    /// assert!(DrmFourcc::Big_endian.info().is_none());
+    /// ```
+    pub fn info(&self) -> Option<FormatInfo> {
+        self.layout()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsampled_planes_round_up() {
+        let info = DrmFourcc::Nv12.info().unwrap();
+        assert_eq!(info.plane_width(1, 7), 4);
+        assert_eq!(info.plane_height(1, 5), 3);
+        assert_eq!(info.min_pitch(1, 7), 8);
+    }
+
+    #[test]
+    fn block_formats_have_per_line_pitch() {
+        let info = DrmFourcc::X0l0.info().unwrap();
+        assert_eq!(info.min_pitch(0, 4), 8);
+
+        let info = DrmFourcc::Nv15.info().unwrap();
+        assert_eq!(info.min_pitch(0, 8), 10);
+        assert_eq!(info.min_pitch(1, 8), 10);
+    }
+
+    #[test]
+    fn min_pitch_of_missing_plane_is_zero() {
+        let info = DrmFourcc::Xrgb8888.info().unwrap();
+        assert_eq!(info.min_pitch(1, 1920), 0);
+        assert_eq!(info.block_width(4), 0);
+    }
+}
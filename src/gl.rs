@@ -0,0 +1,154 @@
+//! Mapping of [`DrmFourcc`] to OpenGL ES pixel transfer parameters, for uploading shm buffers
+//! with `glTexImage2D`.
+//!
+//! DRM formats describe a little-endian word: [`DrmFourcc::Abgr8888`] has red in the lowest byte,
+//! so it is stored in memory as the bytes R, G, B, A, which is what GL calls `GL_RGBA` with
+//! `GL_UNSIGNED_BYTE`. Packed GL types such as `GL_UNSIGNED_SHORT_5_6_5` are read in native
+//! endianness, so they only match the DRM formats on little-endian machines.
+//!
+//! Internal formats are the sized formats of OpenGL ES 3, except for the BGRA formats which need
+//! `GL_EXT_texture_format_BGRA8888`. The 16 bit normalized formats need `GL_EXT_texture_norm16`.
+//!
+//! X formats are uploaded with their padding as alpha, which has to be ignored when sampling.
+use crate::DrmFourcc;
+
+const GL_UNSIGNED_BYTE: u32 = 0x1401;
+const GL_UNSIGNED_SHORT: u32 = 0x1403;
+const GL_HALF_FLOAT: u32 = 0x140B;
+const GL_UNSIGNED_SHORT_4_4_4_4: u32 = 0x8033;
+const GL_UNSIGNED_SHORT_5_5_5_1: u32 = 0x8034;
+const GL_UNSIGNED_SHORT_5_6_5: u32 = 0x8363;
+const GL_UNSIGNED_INT_2_10_10_10_REV: u32 = 0x8368;
+
+const GL_RED: u32 = 0x1903;
+const GL_RGB: u32 = 0x1907;
+const GL_RGBA: u32 = 0x1908;
+const GL_RG: u32 = 0x8227;
+const GL_BGRA_EXT: u32 = 0x80E1;
+
+const GL_RGB8: u32 = 0x8051;
+const GL_RGBA4: u32 = 0x8056;
+const GL_RGB5_A1: u32 = 0x8057;
+const GL_RGBA8: u32 = 0x8058;
+const GL_RGB10_A2: u32 = 0x8059;
+const GL_RGBA16_EXT: u32 = 0x805B;
+const GL_R8: u32 = 0x8229;
+const GL_R16_EXT: u32 = 0x822A;
+const GL_RG8: u32 = 0x822B;
+const GL_RG16_EXT: u32 = 0x822C;
+const GL_RGBA16F: u32 = 0x881A;
+const GL_RGB565: u32 = 0x8D62;
+
+/// The arguments to pass to `glTexImage2D` for a format
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlFormat {
+    /// The `internalformat` argument, a sized format such as `GL_RGBA8`
+    pub internal_format: u32,
+    /// The `format` argument, such as `GL_RGBA`
+    pub format: u32,
+    /// The `type` argument, such as `GL_UNSIGNED_BYTE`
+    pub ty: u32,
+}
+
+impl GlFormat {
+    const fn new(internal_format: u32, format: u32, ty: u32) -> Self {
+        GlFormat {
+            internal_format,
+            format,
+            ty,
+        }
+    }
+}
+
+impl DrmFourcc {
+    /// The GL format to upload this format with.
+    ///
+    /// Returns `None` for formats that can't be uploaded directly, such as YUV formats, which have
+    /// to be imported as an EGLImage instead.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// # const GL_UNSIGNED_BYTE: u32 = 0x1401;
+    /// # const GL_RGBA: u32 = 0x1908;
+    /// let format = DrmFourcc::Abgr8888.gl_format().unwrap();
+    /// assert_eq!(format.format, GL_RGBA);
+    /// assert_eq!(format.ty, GL_UNSIGNED_BYTE);
+    ///
+    /// assert_eq!(DrmFourcc::Nv12.gl_format(), None);
+    /// ```
+    pub fn gl_format(&self) -> Option<GlFormat> {
+        use DrmFourcc::*;
+
+        Some(match self {
+            Argb8888 | Xrgb8888 => GlFormat::new(GL_BGRA_EXT, GL_BGRA_EXT, GL_UNSIGNED_BYTE),
+            Abgr8888 | Xbgr8888 => GlFormat::new(GL_RGBA8, GL_RGBA, GL_UNSIGNED_BYTE),
+            Bgr888 => GlFormat::new(GL_RGB8, GL_RGB, GL_UNSIGNED_BYTE),
+            Rgba4444 | Rgbx4444 => GlFormat::new(GL_RGBA4, GL_RGBA, GL_UNSIGNED_SHORT_4_4_4_4),
+            Rgba5551 | Rgbx5551 => GlFormat::new(GL_RGB5_A1, GL_RGBA, GL_UNSIGNED_SHORT_5_5_5_1),
+            Rgb565 => GlFormat::new(GL_RGB565, GL_RGB, GL_UNSIGNED_SHORT_5_6_5),
+            Abgr2101010 | Xbgr2101010 => {
+                GlFormat::new(GL_RGB10_A2, GL_RGBA, GL_UNSIGNED_INT_2_10_10_10_REV)
+            }
+            Abgr16161616 | Xbgr16161616 => GlFormat::new(GL_RGBA16_EXT, GL_RGBA, GL_UNSIGNED_SHORT),
+            Abgr16161616f | Xbgr16161616f => GlFormat::new(GL_RGBA16F, GL_RGBA, GL_HALF_FLOAT),
+            R8 => GlFormat::new(GL_R8, GL_RED, GL_UNSIGNED_BYTE),
+            Gr88 => GlFormat::new(GL_RG8, GL_RG, GL_UNSIGNED_BYTE),
+            R16 => GlFormat::new(GL_R16_EXT, GL_RED, GL_UNSIGNED_SHORT),
+            Gr1616 => GlFormat::new(GL_RG16_EXT, GL_RG, GL_UNSIGNED_SHORT),
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Bytes per pixel of a format and type combination
+    fn bytes_per_pixel(format: GlFormat) -> u8 {
+        let components = match format.format {
+            GL_RED => 1,
+            GL_RG => 2,
+            GL_RGB => 3,
+            _ => 4,
+        };
+
+        match format.ty {
+            GL_UNSIGNED_BYTE => components,
+            GL_UNSIGNED_SHORT | GL_HALF_FLOAT => components * 2,
+            GL_UNSIGNED_INT_2_10_10_10_REV => 4,
+            _ => 2,
+        }
+    }
+
+    #[test]
+    fn sizes_match_format_info() {
+        let codes = [
+            DrmFourcc::Argb8888,
+            DrmFourcc::Xbgr8888,
+            DrmFourcc::Bgr888,
+            DrmFourcc::Rgbx4444,
+            DrmFourcc::Rgba5551,
+            DrmFourcc::Rgb565,
+            DrmFourcc::Abgr2101010,
+            DrmFourcc::Xbgr16161616,
+            DrmFourcc::Abgr16161616f,
+            DrmFourcc::R8,
+            DrmFourcc::Gr88,
+            DrmFourcc::R16,
+            DrmFourcc::Gr1616,
+        ];
+
+        for &code in codes.iter() {
+            let format = code.gl_format().unwrap();
+            let info = code.info().unwrap();
+            assert_eq!(
+                bytes_per_pixel(format),
+                info.char_per_block[0],
+                "{:?}",
+                code
+            );
+        }
+    }
+}
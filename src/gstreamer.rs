@@ -0,0 +1,219 @@
+//! Support for the GStreamer `DMA_DRM` caps, see `GstVideoInfoDmaDrm`.
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{DrmFormat, DrmFourcc, DrmModifier, ParseFourccError};
+
+/// The `GstVideoFormat` name of every format GStreamer can describe
+const GST_VIDEO_FORMATS: &[(DrmFourcc, &str)] = &[
+    (DrmFourcc::Argb8888, "BGRA"),
+    (DrmFourcc::Xrgb8888, "BGRx"),
+    (DrmFourcc::Abgr8888, "RGBA"),
+    (DrmFourcc::Xbgr8888, "RGBx"),
+    (DrmFourcc::Bgra8888, "ARGB"),
+    (DrmFourcc::Bgrx8888, "xRGB"),
+    (DrmFourcc::Rgba8888, "ABGR"),
+    (DrmFourcc::Rgbx8888, "xBGR"),
+    (DrmFourcc::Rgb888, "BGR"),
+    (DrmFourcc::Bgr888, "RGB"),
+    (DrmFourcc::Rgb565, "RGB16"),
+    (DrmFourcc::Bgr565, "BGR16"),
+    (DrmFourcc::Argb2101010, "BGR10A2_LE"),
+    (DrmFourcc::Abgr2101010, "RGB10A2_LE"),
+    (DrmFourcc::Xrgb2101010, "BGR10x2_LE"),
+    (DrmFourcc::Xbgr2101010, "RGB10x2_LE"),
+    (DrmFourcc::Abgr16161616, "RGBA64_LE"),
+    (DrmFourcc::Argb16161616, "BGRA64_LE"),
+    (DrmFourcc::R8, "GRAY8"),
+    (DrmFourcc::R16, "GRAY16_LE"),
+    (DrmFourcc::Yuyv, "YUY2"),
+    (DrmFourcc::Yvyu, "YVYU"),
+    (DrmFourcc::Uyvy, "UYVY"),
+    (DrmFourcc::Vyuy, "VYUY"),
+    (DrmFourcc::Ayuv, "VUYA"),
+    (DrmFourcc::Y210, "Y210"),
+    (DrmFourcc::Y212, "Y212_LE"),
+    (DrmFourcc::Y410, "Y410"),
+    (DrmFourcc::Nv12, "NV12"),
+    (DrmFourcc::Nv21, "NV21"),
+    (DrmFourcc::Nv16, "NV16"),
+    (DrmFourcc::Nv61, "NV61"),
+    (DrmFourcc::Nv24, "NV24"),
+    (DrmFourcc::Nv15, "NV12_10LE40"),
+    (DrmFourcc::P010, "P010_10LE"),
+    (DrmFourcc::P012, "P012_LE"),
+    (DrmFourcc::P016, "P016_LE"),
+    (DrmFourcc::Yuv410, "YUV9"),
+    (DrmFourcc::Yvu410, "YVU9"),
+    (DrmFourcc::Yuv411, "Y41B"),
+    (DrmFourcc::Yuv420, "I420"),
+    (DrmFourcc::Yvu420, "YV12"),
+    (DrmFourcc::Yuv422, "Y42B"),
+    (DrmFourcc::Yuv444, "Y444"),
+];
+
+impl DrmFourcc {
+    /// Get the name of the `GstVideoFormat` with the same layout
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// assert_eq!(DrmFourcc::Xrgb8888.gst_video_format(), Some("BGRx"));
+    /// assert_eq!(DrmFourcc::P010.gst_video_format(), Some("P010_10LE"));
+    /// assert_eq!(DrmFourcc::C8.gst_video_format(), None);
+    /// ```
+    pub fn gst_video_format(&self) -> Option<&'static str> {
+        GST_VIDEO_FORMATS
+            .iter()
+            .find(|(code, _)| code == self)
+            .map(|&(_, name)| name)
+    }
+
+    /// Look up a format by the name of its `GstVideoFormat`
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// assert_eq!(DrmFourcc::from_gst_video_format("I420"), Some(DrmFourcc::Yuv420));
+    /// assert_eq!(DrmFourcc::from_gst_video_format("DMA_DRM"), None);
+    /// ```
+    pub fn from_gst_video_format(name: &str) -> Option<Self> {
+        GST_VIDEO_FORMATS
+            .iter()
+            .find(|&&(_, gst_name)| gst_name == name)
+            .map(|&(code, _)| code)
+    }
+}
+
+impl DrmFormat {
+    /// Format as the `drm-format` field of `DMA_DRM` caps
+    ///
+    /// The fourcc is followed by the modifier in hex, unless the modifier is linear.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFormat, DrmFourcc, DrmModifier};
+    /// let format = DrmFormat {
+    ///     code: DrmFourcc::Nv12,
+    ///     modifier: DrmModifier::I915_x_tiled,
+    /// };
+    /// assert_eq!(format.gst_drm_format().to_string(), "NV12:0x0100000000000001");
+    ///
+    /// let format = DrmFormat {
+    ///     code: DrmFourcc::Nv12,
+    ///     modifier: DrmModifier::Linear,
+    /// };
+    /// assert_eq!(format.gst_drm_format().to_string(), "NV12");
+    /// ```
+    pub fn gst_drm_format(&self) -> impl Display {
+        GstDrmFormat(*self)
+    }
+
+    /// Parse the `drm-format` field of `DMA_DRM` caps
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFormat, DrmFourcc, DrmModifier};
+    /// let format = DrmFormat::from_gst_drm_format("NV12:0x0100000000000002").unwrap();
+    /// assert_eq!(format.code, DrmFourcc::Nv12);
+    /// assert_eq!(format.modifier, DrmModifier::I915_y_tiled);
+    ///
+    /// let format = DrmFormat::from_gst_drm_format("XR24").unwrap();
+    /// assert_eq!(format.modifier, DrmModifier::Linear);
+    /// ```
+    pub fn from_gst_drm_format(s: &str) -> Result<DrmFormat, ParseGstDrmFormatError> {
+        let (code, modifier) = match s.find(':') {
+            Some(index) => (&s[..index], Some(&s[index + 1..])),
+            None => (s, None),
+        };
+
+        let code = code.parse().map_err(ParseGstDrmFormatError::Fourcc)?;
+        let modifier = match modifier {
+            Some(modifier) => {
+                let digits = modifier
+                    .strip_prefix("0x")
+                    .or_else(|| modifier.strip_prefix("0X"))
+                    .unwrap_or(modifier);
+                if digits.is_empty() || digits.starts_with('+') {
+                    return Err(ParseGstDrmFormatError::Modifier);
+                }
+
+                u64::from_str_radix(digits, 16)
+                    .map_err(|_| ParseGstDrmFormatError::Modifier)?
+                    .into()
+            }
+            None => DrmModifier::Linear,
+        };
+
+        Ok(DrmFormat { code, modifier })
+    }
+}
+
+struct GstDrmFormat(DrmFormat);
+
+impl Display for GstDrmFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0.code, f)?;
+        if self.0.modifier != DrmModifier::Linear {
+            write!(f, ":{:#018x}", u64::from(self.0.modifier))?;
+        }
+        Ok(())
+    }
+}
+
+/// Reasons a string can't be parsed as the `drm-format` field of `DMA_DRM` caps
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseGstDrmFormatError {
+    /// The part before the `:` isn't a DRM fourcc
+    Fourcc(ParseFourccError),
+    /// The part after the `:` isn't a hex modifier
+    Modifier,
+}
+
+impl Display for ParseGstDrmFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseGstDrmFormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Fourcc(err) => Some(err),
+            Self::Modifier => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn video_format_names_are_unique() {
+        for &(code, name) in GST_VIDEO_FORMATS {
+            assert_eq!(code.gst_video_format(), Some(name));
+            assert_eq!(DrmFourcc::from_gst_video_format(name), Some(code));
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_drm_format() {
+        assert_eq!(
+            DrmFormat::from_gst_drm_format("NV12:"),
+            Err(ParseGstDrmFormatError::Modifier)
+        );
+        assert_eq!(
+            DrmFormat::from_gst_drm_format("NV12:0x+1"),
+            Err(ParseGstDrmFormatError::Modifier)
+        );
+        assert_eq!(
+            DrmFormat::from_gst_drm_format("NV12:0x10000000000000000"),
+            Err(ParseGstDrmFormatError::Modifier)
+        );
+        assert_eq!(
+            DrmFormat::from_gst_drm_format("NV123:0x0"),
+            Err(ParseGstDrmFormatError::Fourcc(ParseFourccError::Malformed))
+        );
+    }
+}
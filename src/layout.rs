@@ -0,0 +1,206 @@
+//! Memory layout of linear buffers.
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{DrmFormat, DrmFourcc, DrmModifier};
+
+/// Location and stride of a single plane within a linear buffer.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlaneLayout {
+    /// Byte offset of the plane from the start of the buffer
+    pub offset: u64,
+    /// Number of bytes between the start of two consecutive lines
+    pub pitch: u32,
+    /// Number of bytes occupied by the plane
+    pub size: u64,
+}
+
+/// Layout of every plane of a linear buffer, as computed by [`DrmFormat::linear_layout`].
+///
+/// Planes are stored back to back in a single allocation, in plane order.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearLayout {
+    num_planes: u8,
+    planes: [PlaneLayout; 4],
+    size: u64,
+}
+
+impl LinearLayout {
+    /// The layout of each plane.
+    pub fn planes(&self) -> &[PlaneLayout] {
+        &self.planes[..self.num_planes as usize]
+    }
+
+    /// Total number of bytes needed to hold all planes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// Reasons a linear layout can't be computed
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LayoutError {
+    /// The modifier isn't [`DrmModifier::Linear`]
+    NotLinear(DrmModifier),
+    /// The format has no uncompressed representation, or isn't a pixel format at all
+    NoLinearLayout(DrmFourcc),
+    /// The pitch doesn't fit in an u32, or the size doesn't fit in an u64
+    Overflow,
+}
+
+impl Display for LayoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for LayoutError {}
+
+impl DrmFormat {
+    /// Compute the pitch, offset and size of each plane of a linear buffer of the given dimensions.
+    ///
+    /// Each pitch is rounded up to a multiple of `align` bytes; an `align` of 0 or 1 leaves the
+    /// pitch at its minimum. Subsampled planes and dimensions that aren't a multiple of the block
+    /// size are rounded up, so the buffer always covers every pixel.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFormat, DrmFourcc, DrmModifier};
+    /// let format = DrmFormat {
+    ///     code: DrmFourcc::Nv12,
+    ///     modifier: DrmModifier::Linear,
+    /// };
+    /// let layout = format.linear_layout(1919, 1079, 64).unwrap();
+    ///
+    /// assert_eq!(layout.planes()[0].pitch, 1920);
+    /// assert_eq!(layout.planes()[1].offset, 1920 * 1079);
+    /// assert_eq!(layout.planes()[1].pitch, 1920);
+    /// assert_eq!(layout.size(), 1920 * 1079 + 1920 * 540);
+    /// ```
+    pub fn linear_layout(
+        &self,
+        width: u32,
+        height: u32,
+        align: u32,
+    ) -> Result<LinearLayout, LayoutError> {
+        if self.modifier != DrmModifier::Linear {
+            return Err(LayoutError::NotLinear(self.modifier));
+        }
+
+        let info = self
+            .code
+            .info()
+            .filter(|info| {
+                info.char_per_block[..info.num_planes as usize]
+                    .iter()
+                    .all(|&c| c != 0)
+            })
+            .ok_or(LayoutError::NoLinearLayout(self.code))?;
+        let align = u64::from(align.max(1));
+
+        let mut layout = LinearLayout {
+            num_planes: info.num_planes,
+            planes: [PlaneLayout::default(); 4],
+            size: 0,
+        };
+
+        for plane in 0..info.num_planes as usize {
+            let block_width = info.block_width(plane);
+            let block_height = info.block_height(plane);
+
+            // Pad the plane to whole blocks, expressed back in buffer pixels for min_pitch.
+            let plane_width = round_up(info.plane_width(plane, width), block_width)?;
+            let buffer_width = if plane == 0 {
+                plane_width
+            } else {
+                plane_width
+                    .checked_mul(info.hsub.into())
+                    .ok_or(LayoutError::Overflow)?
+            };
+            let lines = round_up(info.plane_height(plane, height), block_height)?;
+
+            let pitch = info.min_pitch(plane, buffer_width).div_ceil(align) * align;
+            let pitch = u32::try_from(pitch).map_err(|_| LayoutError::Overflow)?;
+            let size = u64::from(pitch)
+                .checked_mul(lines.into())
+                .ok_or(LayoutError::Overflow)?;
+
+            layout.planes[plane] = PlaneLayout {
+                offset: layout.size,
+                pitch,
+                size,
+            };
+            layout.size = layout.size.checked_add(size).ok_or(LayoutError::Overflow)?;
+        }
+
+        Ok(layout)
+    }
+}
+
+fn round_up(n: u32, multiple: u32) -> Result<u32, LayoutError> {
+    n.div_ceil(multiple)
+        .checked_mul(multiple)
+        .ok_or(LayoutError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linear(code: DrmFourcc) -> DrmFormat {
+        DrmFormat {
+            code,
+            modifier: DrmModifier::Linear,
+        }
+    }
+
+    #[test]
+    fn odd_dimensions_round_up_chroma() {
+        let layout = linear(DrmFourcc::Yuv410).linear_layout(17, 9, 1).unwrap();
+        let planes = layout.planes();
+
+        assert_eq!(planes.len(), 3);
+        assert_eq!((planes[0].pitch, planes[0].size), (17, 17 * 9));
+        assert_eq!((planes[1].pitch, planes[1].size), (5, 5 * 3));
+        assert_eq!(planes[2].offset, 17 * 9 + 5 * 3);
+        assert_eq!(layout.size(), 17 * 9 + 2 * 5 * 3);
+    }
+
+    #[test]
+    fn p010_uses_two_bytes_per_sample() {
+        let layout = linear(DrmFourcc::P010).linear_layout(3, 3, 1).unwrap();
+        let planes = layout.planes();
+
+        assert_eq!((planes[0].pitch, planes[0].size), (6, 18));
+        assert_eq!((planes[1].pitch, planes[1].size), (8, 16));
+    }
+
+    #[test]
+    fn block_formats_cover_partial_blocks() {
+        let layout = linear(DrmFourcc::X0l0).linear_layout(3, 3, 1).unwrap();
+        assert_eq!(layout.planes()[0].pitch, 8);
+        assert_eq!(layout.size(), 32);
+    }
+
+    #[test]
+    fn rejects_non_linear_and_compressed_only() {
+        let format = DrmFormat {
+            code: DrmFourcc::Xrgb8888,
+            modifier: DrmModifier::I915_x_tiled,
+        };
+        assert_eq!(
+            format.linear_layout(16, 16, 1),
+            Err(LayoutError::NotLinear(DrmModifier::I915_x_tiled))
+        );
+        assert_eq!(
+            linear(DrmFourcc::Yuv420_8bit).linear_layout(16, 16, 1),
+            Err(LayoutError::NoLinearLayout(DrmFourcc::Yuv420_8bit))
+        );
+    }
+}
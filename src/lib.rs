@@ -42,6 +42,7 @@
 //! - `serde` - Derive Serialize/Deserialize where it makes sense
 //! - `build_bindings` - Re-generate autogenerated code. Useful if you need varients added in a
 //!     more recent kernel version.
+//! - `vulkan` - Map formats to and from raw `VkFormat` values
 //!
 //! [fourcc_wiki]: https://en.wikipedia.org/wiki/FourCC
 //! [drm_wiki]: https://en.wikipedia.org/wiki/Direct_Rendering_Managerz
@@ -51,6 +52,7 @@ use core::convert::TryFrom;
 use core::fmt;
 use core::fmt::{Debug, Display, Formatter};
 use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
 #[cfg(feature = "std")]
 use std::string::{String, ToString};
@@ -58,10 +60,52 @@ use std::string::{String, ToString};
 #[cfg(feature = "std")]
 use std::error::Error;
 
+pub use amd::AmdModifier;
+pub use arm::{AfbcBlockSize, ArmAfbcModifier, InvalidAfbcModifier};
 pub use as_enum::{DrmFourcc, DrmModifier, DrmVendor};
-
+pub use blob::{FormatModifierBlob, FormatModifierBlobIter, InvalidBlob};
+pub use broadcom::{BroadcomSand, BroadcomSandWidth};
+pub use format_info::FormatInfo;
+pub use gl::GlFormat;
+pub use gstreamer::ParseGstDrmFormatError;
+pub use layout::{LayoutError, LinearLayout, PlaneLayout};
+pub use nvidia::NvidiaBlockLinear;
+#[cfg(feature = "std")]
+pub use set::{DrmFormatSet, DrmFormatSetIter};
+#[cfg(feature = "std")]
+pub use spa::spa_modifier_choice;
+pub use spa::{formats_from_spa_choice, parse_spa_modifier_choice};
+pub use tiling::{
+    tile, tile_geometry, tile_uif, untile, untile_uif, TileGeometry, TilingError, UifLayout,
+};
+pub use v4l2::is_v4l2_multiplanar;
+#[cfg(feature = "vulkan")]
+pub use vulkan::{VkComponentMapping, VkComponentSwizzle, VkFormatMapping};
+
+mod amd;
+mod android;
+mod arm;
+// Modifier values can be shared by several names, such as the Samsung and generic 16x16 tiles.
+#[allow(clippy::match_overlapping_arm)]
 mod as_enum;
+mod blob;
+mod broadcom;
+mod cairo;
 mod consts;
+mod ffmpeg;
+mod format_info;
+mod gl;
+mod gstreamer;
+mod layout;
+mod nvidia;
+mod pixman;
+#[cfg(feature = "std")]
+mod set;
+mod spa;
+mod tiling;
+mod v4l2;
+#[cfg(feature = "vulkan")]
+mod vulkan;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -119,6 +163,169 @@ impl TryFrom<u32> for DrmFourcc {
     }
 }
 
+impl DrmFourcc {
+    /// Convert from the four bytes of the fourcc
+    ///
+    /// NUL bytes after the leading two are treated as spaces, mirroring how the fourcc is
+    /// displayed.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFourcc, UnrecognizedFourcc};
+    /// assert_eq!(DrmFourcc::from_bytes(*b"XR24"), Ok(DrmFourcc::Xrgb8888));
+    /// assert_eq!(DrmFourcc::from_bytes(*b"C8\0\0"), Ok(DrmFourcc::C8));
+    /// assert_eq!(
+    ///     DrmFourcc::from_bytes(*b"avc1"),
+    ///     Err(UnrecognizedFourcc(828601953))
+    /// );
+    /// ```
+    pub const fn from_bytes(bytes: [u8; 4]) -> Result<Self, UnrecognizedFourcc> {
+        let mut bytes = bytes;
+        let mut i = 2;
+        while i < 4 {
+            if bytes[i] == b'\0' {
+                bytes[i] = b' ';
+            }
+            i += 1;
+        }
+
+        let value = u32::from_le_bytes(bytes);
+        match Self::from_u32(value) {
+            Some(fourcc) => Ok(fourcc),
+            None => Err(UnrecognizedFourcc(value)),
+        }
+    }
+
+    /// Get the name of the format's macro in `drm_fourcc.h`
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// assert_eq!(DrmFourcc::Xrgb8888.name(), "DRM_FORMAT_XRGB8888");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        self.names().0
+    }
+
+    /// Get the name of the format's macro without the `DRM_FORMAT_` prefix
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// assert_eq!(DrmFourcc::Xrgb8888.short_name(), "XRGB8888");
+    /// ```
+    pub fn short_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// Look up a format by either its [`name`](Self::name) or its
+    /// [`short_name`](Self::short_name)
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// assert_eq!(DrmFourcc::from_name("DRM_FORMAT_NV12"), Some(DrmFourcc::Nv12));
+    /// assert_eq!(DrmFourcc::from_name("NV12"), Some(DrmFourcc::Nv12));
+    /// assert_eq!(DrmFourcc::from_name("nv12"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::from_any_name(name)
+    }
+
+    /// Convert to a `wl_shm.format` code
+    ///
+    /// `wl_shm` uses the fourcc for every format except ARGB8888 and XRGB8888, which are 0 and 1.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// assert_eq!(DrmFourcc::Argb8888.to_wl_shm(), 0);
+    /// assert_eq!(DrmFourcc::Xrgb8888.to_wl_shm(), 1);
+    /// assert_eq!(DrmFourcc::Nv12.to_wl_shm(), DrmFourcc::Nv12 as u32);
+    /// ```
+    pub const fn to_wl_shm(&self) -> u32 {
+        match self {
+            Self::Argb8888 => 0,
+            Self::Xrgb8888 => 1,
+            _ => *self as u32,
+        }
+    }
+
+    /// Convert from a `wl_shm.format` code
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFourcc, UnrecognizedFourcc};
+    /// assert_eq!(DrmFourcc::from_wl_shm(0), Ok(DrmFourcc::Argb8888));
+    /// assert_eq!(DrmFourcc::from_wl_shm(1), Ok(DrmFourcc::Xrgb8888));
+    /// assert_eq!(DrmFourcc::from_wl_shm(0x3231564e), Ok(DrmFourcc::Nv12));
+    /// assert_eq!(DrmFourcc::from_wl_shm(2), Err(UnrecognizedFourcc(2)));
+    /// ```
+    pub const fn from_wl_shm(value: u32) -> Result<Self, UnrecognizedFourcc> {
+        match value {
+            0 => Ok(Self::Argb8888),
+            1 => Ok(Self::Xrgb8888),
+            _ => match Self::from_u32(value) {
+                Some(fourcc) => Ok(fourcc),
+                None => Err(UnrecognizedFourcc(value)),
+            },
+        }
+    }
+}
+
+impl FromStr for DrmFourcc {
+    type Err = ParseFourccError;
+
+    /// Parse the string form of the fourcc
+    ///
+    /// Codes shorter than four characters are padded with spaces.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFourcc, ParseFourccError, UnrecognizedFourcc};
+    /// assert_eq!("NV12".parse(), Ok(DrmFourcc::Nv12));
+    /// assert_eq!("C8".parse(), Ok(DrmFourcc::C8));
+    ///
+    /// assert_eq!("XRGB8888".parse::<DrmFourcc>(), Err(ParseFourccError::Malformed));
+    /// assert_eq!(
+    ///     "avc1".parse::<DrmFourcc>(),
+    ///     Err(ParseFourccError::Unrecognized(UnrecognizedFourcc(828601953)))
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_ascii() || s.len() < 2 || s.len() > 4 {
+            return Err(ParseFourccError::Malformed);
+        }
+
+        let mut bytes = [b' '; 4];
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+
+        if fourcc_display_form(u32::from_le_bytes(bytes)).is_none() {
+            return Err(ParseFourccError::Malformed);
+        }
+
+        Self::from_bytes(bytes).map_err(ParseFourccError::Unrecognized)
+    }
+}
+
+/// Reasons a string can't be parsed as a [`DrmFourcc`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseFourccError {
+    /// The string isn't a valid fourcc code
+    Malformed,
+    /// The string is a valid fourcc code, but not one of a DRM format
+    Unrecognized(UnrecognizedFourcc),
+}
+
+impl Display for ParseFourccError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseFourccError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Malformed => None,
+            Self::Unrecognized(err) => Some(err),
+        }
+    }
+}
+
 /// Wraps some u32 that isn't a DRM fourcc we recognize
 ///
 #[cfg_attr(feature = "std", doc = "```")]
@@ -271,6 +478,40 @@ impl Display for UnrecognizedVendor {
 #[cfg(feature = "std")]
 impl Error for UnrecognizedVendor {}
 
+impl DrmVendor {
+    /// Get the name of the vendor's macro in `drm_fourcc.h`
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmVendor;
+    /// assert_eq!(DrmVendor::Amd.name(), "DRM_FORMAT_MOD_VENDOR_AMD");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        self.names().0
+    }
+
+    /// Get the name of the vendor's macro without the `DRM_FORMAT_MOD_VENDOR_` prefix
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmVendor;
+    /// assert_eq!(DrmVendor::Amd.short_name(), "AMD");
+    /// ```
+    pub fn short_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// Look up a vendor by either its [`name`](Self::name) or its
+    /// [`short_name`](Self::short_name)
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmVendor;
+    /// assert_eq!(DrmVendor::from_name("DRM_FORMAT_MOD_VENDOR_ARM"), Some(DrmVendor::Arm));
+    /// assert_eq!(DrmVendor::from_name("ARM"), Some(DrmVendor::Arm));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::from_any_name(name)
+    }
+}
+
 impl From<u64> for DrmModifier {
     /// Convert from an u64
     ///
@@ -354,6 +595,44 @@ impl Hash for DrmModifier {
     }
 }
 
+impl Display for DrmModifier {
+    /// Format the modifier in the style of `drm_info`
+    ///
+    /// Modifiers with a kernel macro are printed by its name, parameterized vendor modifiers are
+    /// decoded, and anything else is printed as the vendor followed by the remaining bits.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmModifier;
+    /// assert_eq!(DrmModifier::I915_y_tiled_gen12_rc_ccs.to_string(), "I915_FORMAT_MOD_Y_TILED_GEN12_RC_CCS");
+    /// assert_eq!(DrmModifier::from(0x0800_0000_0000_0051).to_string(), "ARM_AFBC(16x16, YTR, SPARSE)");
+    /// assert_eq!(DrmModifier::from(0x0100_0000_0000_00ff).to_string(), "INTEL:0x000000000000ff");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.name() {
+            return f.write_str(name);
+        }
+        if let Some(amd) = self.amd() {
+            return Display::fmt(&amd, f);
+        }
+        if let Some(afbc) = self.arm_afbc() {
+            return Display::fmt(&afbc, f);
+        }
+        if let Some(block_linear) = self.nvidia_block_linear() {
+            return Display::fmt(&block_linear, f);
+        }
+        if let Some(sand) = self.broadcom_sand() {
+            return Display::fmt(&sand, f);
+        }
+
+        let value = self.into_u64() & ((1 << 56) - 1);
+        match self.vendor() {
+            Ok(Some(vendor)) => write!(f, "{}:{:#016x}", vendor.short_name(), value),
+            Ok(None) => write!(f, "NONE:{:#016x}", value),
+            Err(UnrecognizedVendor(vendor)) => write!(f, "{:#04x}:{:#016x}", vendor, value),
+        }
+    }
+}
+
 impl DrmModifier {
     /// Get the vendor of the modifier, if any
     ///
@@ -371,6 +650,46 @@ impl DrmModifier {
             DrmVendor::try_from(vendor).map(Some)
         }
     }
+
+    /// Get the name of the modifier's macro in `drm_fourcc.h`, if it has one
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmModifier;
+    /// assert_eq!(DrmModifier::Linear.name(), Some("DRM_FORMAT_MOD_LINEAR"));
+    /// assert_eq!(DrmModifier::I915_y_tiled_ccs.name(), Some("I915_FORMAT_MOD_Y_TILED_CCS"));
+    /// assert_eq!(DrmModifier::Unrecognized(42).name(), None);
+    /// ```
+    pub fn name(&self) -> Option<&'static str> {
+        self.names().map(|names| names.0)
+    }
+
+    /// Get the name of the modifier's macro without the `DRM_FORMAT_MOD_` prefix, if it has one
+    ///
+    /// Intel modifiers keep an `I915_` prefix, the rest of `I915_FORMAT_MOD_` is removed.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmModifier;
+    /// assert_eq!(DrmModifier::Linear.short_name(), Some("LINEAR"));
+    /// assert_eq!(DrmModifier::I915_y_tiled_ccs.short_name(), Some("I915_Y_TILED_CCS"));
+    /// ```
+    pub fn short_name(&self) -> Option<&'static str> {
+        self.names().map(|names| names.1)
+    }
+
+    /// Look up a modifier by either its [`name`](Self::name) or its
+    /// [`short_name`](Self::short_name)
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmModifier;
+    /// assert_eq!(
+    ///     DrmModifier::from_name("I915_FORMAT_MOD_X_TILED"),
+    ///     Some(DrmModifier::I915_x_tiled)
+    /// );
+    /// assert_eq!(DrmModifier::from_name("LINEAR"), Some(DrmModifier::Linear));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::from_any_name(name)
+    }
 }
 
 // Bindgen will always insert `use` statements for these types even though we seriously never use
@@ -429,6 +748,98 @@ pub mod tests {
         assert_eq!(UnrecognizedFourcc(0).to_string(), "UnrecognizedFourcc(0)");
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn parses_display_form() {
+        for fourcc in [DrmFourcc::Xrgb8888, DrmFourcc::C8, DrmFourcc::Yuv420_8bit].iter() {
+            assert_eq!(fourcc.to_string().parse(), Ok(*fourcc));
+        }
+    }
+
+    #[test]
+    fn parse_rejects_malformed() {
+        assert_eq!("".parse::<DrmFourcc>(), Err(ParseFourccError::Malformed));
+        assert_eq!(" 8".parse::<DrmFourcc>(), Err(ParseFourccError::Malformed));
+        assert_eq!("é8".parse::<DrmFourcc>(), Err(ParseFourccError::Malformed));
+        assert_eq!("😀".parse::<DrmFourcc>(), Err(ParseFourccError::Malformed));
+        assert_eq!("a😀".parse::<DrmFourcc>(), Err(ParseFourccError::Malformed));
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for fourcc in [
+            DrmFourcc::Xrgb8888,
+            DrmFourcc::Big_endian,
+            DrmFourcc::Yuv420_8bit,
+        ]
+        .iter()
+        {
+            assert_eq!(DrmFourcc::from_name(fourcc.name()), Some(*fourcc));
+            assert_eq!(DrmFourcc::from_name(fourcc.short_name()), Some(*fourcc));
+        }
+
+        // Both share a value, but keep their own name.
+        let samsung = DrmModifier::Samsung_16_16_tile;
+        assert_eq!(samsung.name(), Some("DRM_FORMAT_MOD_SAMSUNG_16_16_TILE"));
+        assert!(matches!(
+            DrmModifier::from_name(samsung.name().unwrap()),
+            Some(DrmModifier::Samsung_16_16_tile)
+        ));
+    }
+
+    #[test]
+    fn wl_shm_codes_round_trip() {
+        assert_eq!(DrmFourcc::Argb8888.to_wl_shm(), 0);
+        assert_eq!(DrmFourcc::Xrgb8888.to_wl_shm(), 1);
+        assert_eq!(DrmFourcc::from_wl_shm(0), Ok(DrmFourcc::Argb8888));
+        assert_eq!(DrmFourcc::from_wl_shm(1), Ok(DrmFourcc::Xrgb8888));
+
+        // Every other format keeps its fourcc. Apart from the big-endian flag, those are all
+        // alphanumeric or space.
+        let big_endian = DrmFourcc::Big_endian;
+        assert_eq!(big_endian.to_wl_shm(), big_endian as u32);
+        assert_eq!(DrmFourcc::from_wl_shm(big_endian as u32), Ok(big_endian));
+
+        let chars = b" 0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+        for &a in chars.iter() {
+            for &b in chars.iter() {
+                for &c in chars.iter() {
+                    for &d in chars.iter() {
+                        let code = u32::from_le_bytes([a, b, c, d]);
+                        let fourcc = match DrmFourcc::try_from(code) {
+                            Ok(DrmFourcc::Argb8888) | Ok(DrmFourcc::Xrgb8888) | Err(_) => continue,
+                            Ok(fourcc) => fourcc,
+                        };
+                        assert_eq!(fourcc.to_wl_shm(), code);
+                        assert_eq!(DrmFourcc::from_wl_shm(code), Ok(fourcc));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn modifier_display_falls_back_to_hex() {
+        assert_eq!(DrmModifier::Linear.to_string(), "DRM_FORMAT_MOD_LINEAR");
+        assert_eq!(
+            DrmModifier::from(0x0200_0000_0000_1b02).to_string(),
+            "AMD(GFX10, 64K_R_X)"
+        );
+        assert_eq!(
+            DrmModifier::from(0x0700_0000_0000_6004).to_string(),
+            "BROADCOM_SAND128_COL_HEIGHT(96)"
+        );
+        assert_eq!(
+            DrmModifier::from(0x0000_0000_0000_0002).to_string(),
+            "NONE:0x00000000000002"
+        );
+        assert_eq!(
+            DrmModifier::from(0x7800_0000_0000_0001).to_string(),
+            "0x78:0x00000000000001"
+        );
+    }
+
     #[test]
     fn can_clone_result() {
         let a = DrmFourcc::try_from(0);
@@ -0,0 +1,284 @@
+//! Decoding of NVIDIA block linear modifiers, see `DRM_FORMAT_MOD_NVIDIA_BLOCK_LINEAR_2D` in
+//! `drm_fourcc.h`.
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+use crate::{DrmModifier, DrmVendor};
+
+const VENDOR_SHIFT: u32 = 56;
+
+const LOG2_BLOCK_HEIGHT_MASK: u64 = 0xf;
+const BLOCK_LINEAR: u64 = 0x10;
+const PAGE_KIND_SHIFT: u32 = 12;
+const PAGE_KIND_MASK: u64 = 0xff;
+const GOB_KIND_SHIFT: u32 = 20;
+const GOB_KIND_MASK: u64 = 0x3;
+const SECTOR_LAYOUT_SHIFT: u32 = 22;
+const SECTOR_LAYOUT_MASK: u64 = 0x1;
+const COMPRESSION_SHIFT: u32 = 23;
+const COMPRESSION_MASK: u64 = 0x7;
+
+/// Every bit that isn't either a parameter, the block linear marker or the vendor.
+const RESERVED_MASK: u64 = (((1 << VENDOR_SHIFT) - 1) & !((1 << 26) - 1)) | 0x1e0 | 0xe00;
+
+/// The parameters of an NVIDIA 2D block linear modifier.
+///
+/// The legacy `Nvidia_16bx2_block_*_gob` modifiers are the special case where every parameter but
+/// the block height is zero. Page kind 0 is not usable with block linear layouts, so drivers
+/// treat it as [`NvidiaBlockLinear::PAGE_KIND_GENERIC`], see [`NvidiaBlockLinear::is_equivalent`].
+///
+/// ```
+/// # use drm_fourcc::{DrmModifier, NvidiaBlockLinear};
+/// let block_linear = DrmModifier::Nvidia_16bx2_block_four_gob.nvidia_block_linear().unwrap();
+///
+/// assert_eq!(block_linear.log2_block_height, 2);
+/// assert_eq!(block_linear.block_height_gobs(), 4);
+/// assert!(block_linear.is_legacy());
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NvidiaBlockLinear {
+    /// Lossless compression type, one of the `COMPRESSION_*` constants (`c`)
+    pub compression: u8,
+    /// Sector layout, one of the `SECTOR_LAYOUT_*` constants (`s`)
+    pub sector_layout: u8,
+    /// GOB height and page kind generation, one of the `GOB_KIND_*` constants (`g`)
+    pub gob_kind_generation: u8,
+    /// Page kind, whose meaning depends on the generation (`k`)
+    pub page_kind: u8,
+    /// log2 of the height of a block, in GOBs (`h`)
+    pub log2_block_height: u8,
+}
+
+impl NvidiaBlockLinear {
+    pub const COMPRESSION_NONE: u8 = 0;
+    pub const COMPRESSION_ROP_3D_1: u8 = 1;
+    pub const COMPRESSION_ROP_3D_2: u8 = 2;
+    pub const COMPRESSION_CDE_HORIZONTAL: u8 = 3;
+    pub const COMPRESSION_CDE_VERTICAL: u8 = 4;
+
+    /// Tegra K1 to Tegra Parker/TX2
+    pub const SECTOR_LAYOUT_TEGRA: u8 = 0;
+    /// Desktop GPUs and Tegra Xavier onwards
+    pub const SECTOR_LAYOUT_DESKTOP: u8 = 1;
+
+    /// 8 lines per GOB, Fermi to Volta and Tegra K1 onwards
+    pub const GOB_KIND_FERMI: u8 = 0;
+    /// 4 lines per GOB, G80 to GT2XX
+    pub const GOB_KIND_G80: u8 = 1;
+    /// 8 lines per GOB, Turing onwards
+    pub const GOB_KIND_TURING: u8 = 2;
+
+    /// The page kind used for single-sample uncompressed color on Fermi to Volta
+    pub const PAGE_KIND_GENERIC: u8 = 0xfe;
+
+    /// Decode a block linear modifier from its u64 form.
+    ///
+    /// Returns `None` if the vendor isn't [`DrmVendor::Nvidia`], the modifier doesn't describe a
+    /// block linear layout (such as [`DrmModifier::Nvidia_tegra_tiled`]), or reserved bits are set.
+    pub fn from_u64(value: u64) -> Option<Self> {
+        if (value >> VENDOR_SHIFT) as u8 != DrmVendor::Nvidia as u8
+            || value & BLOCK_LINEAR == 0
+            || value & RESERVED_MASK != 0
+        {
+            return None;
+        }
+
+        let field = |shift: u32, mask: u64| ((value >> shift) & mask) as u8;
+
+        Some(NvidiaBlockLinear {
+            compression: field(COMPRESSION_SHIFT, COMPRESSION_MASK),
+            sector_layout: field(SECTOR_LAYOUT_SHIFT, SECTOR_LAYOUT_MASK),
+            gob_kind_generation: field(GOB_KIND_SHIFT, GOB_KIND_MASK),
+            page_kind: field(PAGE_KIND_SHIFT, PAGE_KIND_MASK),
+            log2_block_height: field(0, LOG2_BLOCK_HEIGHT_MASK),
+        })
+    }
+
+    /// Encode the modifier to its u64 form, truncating parameters that are out of range.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmModifier, NvidiaBlockLinear};
+    /// let block_linear = NvidiaBlockLinear {
+    ///     log2_block_height: 4,
+    ///     ..NvidiaBlockLinear::default()
+    /// };
+    /// assert_eq!(
+    ///     DrmModifier::from(block_linear.into_u64()),
+    ///     DrmModifier::Nvidia_16bx2_block_sixteen_gob
+    /// );
+    /// ```
+    pub fn into_u64(self) -> u64 {
+        let field = |value: u8, shift: u32, mask: u64| (u64::from(value) & mask) << shift;
+
+        (u64::from(DrmVendor::Nvidia as u8) << VENDOR_SHIFT)
+            | BLOCK_LINEAR
+            | field(self.log2_block_height, 0, LOG2_BLOCK_HEIGHT_MASK)
+            | field(self.page_kind, PAGE_KIND_SHIFT, PAGE_KIND_MASK)
+            | field(self.gob_kind_generation, GOB_KIND_SHIFT, GOB_KIND_MASK)
+            | field(self.sector_layout, SECTOR_LAYOUT_SHIFT, SECTOR_LAYOUT_MASK)
+            | field(self.compression, COMPRESSION_SHIFT, COMPRESSION_MASK)
+    }
+
+    /// Height of a block in GOBs.
+    pub fn block_height_gobs(&self) -> u32 {
+        1 << (self.log2_block_height & LOG2_BLOCK_HEIGHT_MASK as u8)
+    }
+
+    /// Is this one of the `DRM_FORMAT_MOD_NVIDIA_16BX2_BLOCK` modifiers?
+    pub fn is_legacy(&self) -> bool {
+        self.compression == 0
+            && self.sector_layout == 0
+            && self.gob_kind_generation == 0
+            && self.page_kind == 0
+    }
+
+    /// Replace the legacy page kind 0 by the generic page kind drivers substitute for it.
+    pub fn normalized(self) -> Self {
+        if self.is_legacy() {
+            NvidiaBlockLinear {
+                page_kind: Self::PAGE_KIND_GENERIC,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+
+    /// Do both modifiers describe the same memory layout?
+    ///
+    /// A legacy modifier is equivalent to the parameterized form with the generic page kind, the
+    /// Tegra sector layout, the Fermi GOB kind and no compression.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmModifier, NvidiaBlockLinear};
+    /// let legacy = DrmModifier::Nvidia_16bx2_block_two_gob.nvidia_block_linear().unwrap();
+    /// let parameterized = NvidiaBlockLinear {
+    ///     page_kind: NvidiaBlockLinear::PAGE_KIND_GENERIC,
+    ///     log2_block_height: 1,
+    ///     ..NvidiaBlockLinear::default()
+    /// };
+    ///
+    /// assert_ne!(legacy, parameterized);
+    /// assert!(legacy.is_equivalent(&parameterized));
+    /// ```
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
+
+impl Display for NvidiaBlockLinear {
+    /// Format as the arguments of `DRM_FORMAT_MOD_NVIDIA_BLOCK_LINEAR_2D`
+    ///
+    /// ```
+    /// # use drm_fourcc::NvidiaBlockLinear;
+    /// let block_linear = NvidiaBlockLinear {
+    ///     sector_layout: NvidiaBlockLinear::SECTOR_LAYOUT_DESKTOP,
+    ///     gob_kind_generation: NvidiaBlockLinear::GOB_KIND_TURING,
+    ///     page_kind: 0x06,
+    ///     log2_block_height: 4,
+    ///     ..NvidiaBlockLinear::default()
+    /// };
+    /// assert_eq!(
+    ///     block_linear.to_string(),
+    ///     "NVIDIA_BLOCK_LINEAR_2D(c=0, s=1, g=2, k=0x06, h=4)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "NVIDIA_BLOCK_LINEAR_2D(c={}, s={}, g={}, k={:#04x}, h={})",
+            self.compression,
+            self.sector_layout,
+            self.gob_kind_generation,
+            self.page_kind,
+            self.log2_block_height
+        )
+    }
+}
+
+impl From<NvidiaBlockLinear> for u64 {
+    fn from(val: NvidiaBlockLinear) -> u64 {
+        val.into_u64()
+    }
+}
+
+impl From<NvidiaBlockLinear> for DrmModifier {
+    fn from(val: NvidiaBlockLinear) -> DrmModifier {
+        DrmModifier::from(val.into_u64())
+    }
+}
+
+impl DrmModifier {
+    /// Decode the parameters of an NVIDIA block linear modifier, legacy or not
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmModifier;
+    /// assert!(DrmModifier::Nvidia_16bx2_block_one_gob.nvidia_block_linear().is_some());
+    /// assert!(DrmModifier::Nvidia_tegra_tiled.nvidia_block_linear().is_none());
+    /// ```
+    pub fn nvidia_block_linear(&self) -> Option<NvidiaBlockLinear> {
+        NvidiaBlockLinear::from_u64(self.into_u64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_every_parameter() {
+        // DRM_FORMAT_MOD_NVIDIA_BLOCK_LINEAR_2D(1, 1, 2, 0x06, 5)
+        let value = 0x0300_0000_00e0_6015;
+        let block_linear = NvidiaBlockLinear::from_u64(value).unwrap();
+
+        assert_eq!(
+            block_linear,
+            NvidiaBlockLinear {
+                compression: NvidiaBlockLinear::COMPRESSION_ROP_3D_1,
+                sector_layout: NvidiaBlockLinear::SECTOR_LAYOUT_DESKTOP,
+                gob_kind_generation: NvidiaBlockLinear::GOB_KIND_TURING,
+                page_kind: 0x06,
+                log2_block_height: 5,
+            }
+        );
+        assert!(!block_linear.is_legacy());
+        assert_eq!(block_linear.into_u64(), value);
+    }
+
+    #[test]
+    fn legacy_constants_are_equivalent_to_generic_kind() {
+        let legacy = [
+            DrmModifier::Nvidia_16bx2_block_one_gob,
+            DrmModifier::Nvidia_16bx2_block_two_gob,
+            DrmModifier::Nvidia_16bx2_block_four_gob,
+            DrmModifier::Nvidia_16bx2_block_eight_gob,
+            DrmModifier::Nvidia_16bx2_block_sixteen_gob,
+            DrmModifier::Nvidia_16bx2_block_thirtytwo_gob,
+        ];
+
+        for (h, modifier) in legacy.iter().enumerate() {
+            let block_linear = modifier.nvidia_block_linear().unwrap();
+            assert_eq!(block_linear.log2_block_height as usize, h);
+            assert!(block_linear.is_legacy());
+
+            let generic = NvidiaBlockLinear {
+                page_kind: NvidiaBlockLinear::PAGE_KIND_GENERIC,
+                ..block_linear
+            };
+            assert!(block_linear.is_equivalent(&generic));
+
+            let desktop = NvidiaBlockLinear {
+                sector_layout: NvidiaBlockLinear::SECTOR_LAYOUT_DESKTOP,
+                ..generic
+            };
+            assert!(!block_linear.is_equivalent(&desktop));
+        }
+    }
+
+    #[test]
+    fn rejects_reserved_bits() {
+        assert_eq!(NvidiaBlockLinear::from_u64(0x0300_0000_0000_0030), None);
+        assert_eq!(NvidiaBlockLinear::from_u64(0x0300_0000_0400_0010), None);
+    }
+}
@@ -0,0 +1,255 @@
+//! Mapping to pixman format codes (`pixman_format_code_t`).
+//!
+//! Codes of packed RGB formats are computed from the channel layout spelled out in the format's
+//! name, the same way `PIXMAN_FORMAT()` builds them. Like DRM formats, pixman's packed formats
+//! are native-endian words, so they match on little-endian machines.
+use crate::DrmFourcc;
+
+const PIXMAN_TYPE_ARGB: u32 = 2;
+const PIXMAN_TYPE_ABGR: u32 = 3;
+const PIXMAN_TYPE_YUY2: u32 = 6;
+const PIXMAN_TYPE_YV12: u32 = 7;
+const PIXMAN_TYPE_BGRA: u32 = 8;
+const PIXMAN_TYPE_RGBA: u32 = 9;
+
+/// Equivalent of the `PIXMAN_FORMAT()` macro
+const fn pixman_format(bpp: u32, ty: u32, a: u32, r: u32, g: u32, b: u32) -> u32 {
+    (bpp << 24) | (ty << 16) | (a << 12) | (r << 8) | (g << 4) | b
+}
+
+pub(crate) const PIXMAN_A8R8G8B8: u32 = pixman_format(32, PIXMAN_TYPE_ARGB, 8, 8, 8, 8);
+pub(crate) const PIXMAN_X8R8G8B8: u32 = pixman_format(32, PIXMAN_TYPE_ARGB, 0, 8, 8, 8);
+const PIXMAN_A8B8G8R8: u32 = pixman_format(32, PIXMAN_TYPE_ABGR, 8, 8, 8, 8);
+const PIXMAN_X8B8G8R8: u32 = pixman_format(32, PIXMAN_TYPE_ABGR, 0, 8, 8, 8);
+const PIXMAN_B8G8R8A8: u32 = pixman_format(32, PIXMAN_TYPE_BGRA, 8, 8, 8, 8);
+const PIXMAN_B8G8R8X8: u32 = pixman_format(32, PIXMAN_TYPE_BGRA, 0, 8, 8, 8);
+const PIXMAN_R8G8B8A8: u32 = pixman_format(32, PIXMAN_TYPE_RGBA, 8, 8, 8, 8);
+const PIXMAN_R8G8B8X8: u32 = pixman_format(32, PIXMAN_TYPE_RGBA, 0, 8, 8, 8);
+pub(crate) const PIXMAN_X2R10G10B10: u32 = pixman_format(32, PIXMAN_TYPE_ARGB, 0, 10, 10, 10);
+const PIXMAN_A2R10G10B10: u32 = pixman_format(32, PIXMAN_TYPE_ARGB, 2, 10, 10, 10);
+const PIXMAN_X2B10G10R10: u32 = pixman_format(32, PIXMAN_TYPE_ABGR, 0, 10, 10, 10);
+const PIXMAN_A2B10G10R10: u32 = pixman_format(32, PIXMAN_TYPE_ABGR, 2, 10, 10, 10);
+const PIXMAN_R8G8B8: u32 = pixman_format(24, PIXMAN_TYPE_ARGB, 0, 8, 8, 8);
+const PIXMAN_B8G8R8: u32 = pixman_format(24, PIXMAN_TYPE_ABGR, 0, 8, 8, 8);
+pub(crate) const PIXMAN_R5G6B5: u32 = pixman_format(16, PIXMAN_TYPE_ARGB, 0, 5, 6, 5);
+const PIXMAN_B5G6R5: u32 = pixman_format(16, PIXMAN_TYPE_ABGR, 0, 5, 6, 5);
+const PIXMAN_A1R5G5B5: u32 = pixman_format(16, PIXMAN_TYPE_ARGB, 1, 5, 5, 5);
+const PIXMAN_X1R5G5B5: u32 = pixman_format(16, PIXMAN_TYPE_ARGB, 0, 5, 5, 5);
+const PIXMAN_A1B5G5R5: u32 = pixman_format(16, PIXMAN_TYPE_ABGR, 1, 5, 5, 5);
+const PIXMAN_X1B5G5R5: u32 = pixman_format(16, PIXMAN_TYPE_ABGR, 0, 5, 5, 5);
+const PIXMAN_A4R4G4B4: u32 = pixman_format(16, PIXMAN_TYPE_ARGB, 4, 4, 4, 4);
+const PIXMAN_X4R4G4B4: u32 = pixman_format(16, PIXMAN_TYPE_ARGB, 0, 4, 4, 4);
+const PIXMAN_A4B4G4R4: u32 = pixman_format(16, PIXMAN_TYPE_ABGR, 4, 4, 4, 4);
+const PIXMAN_X4B4G4R4: u32 = pixman_format(16, PIXMAN_TYPE_ABGR, 0, 4, 4, 4);
+const PIXMAN_R3G3B2: u32 = pixman_format(8, PIXMAN_TYPE_ARGB, 0, 3, 3, 2);
+const PIXMAN_B2G3R3: u32 = pixman_format(8, PIXMAN_TYPE_ABGR, 0, 3, 3, 2);
+const PIXMAN_YUY2: u32 = pixman_format(16, PIXMAN_TYPE_YUY2, 0, 0, 0, 0);
+const PIXMAN_YV12: u32 = pixman_format(12, PIXMAN_TYPE_YV12, 0, 0, 0, 0);
+
+/// The packed RGB codes pixman implements. Others can be computed, but pixman rejects them.
+const PIXMAN_RGB_FORMATS: &[u32] = &[
+    PIXMAN_A8R8G8B8,
+    PIXMAN_X8R8G8B8,
+    PIXMAN_A8B8G8R8,
+    PIXMAN_X8B8G8R8,
+    PIXMAN_B8G8R8A8,
+    PIXMAN_B8G8R8X8,
+    PIXMAN_R8G8B8A8,
+    PIXMAN_R8G8B8X8,
+    PIXMAN_X2R10G10B10,
+    PIXMAN_A2R10G10B10,
+    PIXMAN_X2B10G10R10,
+    PIXMAN_A2B10G10R10,
+    PIXMAN_R8G8B8,
+    PIXMAN_B8G8R8,
+    PIXMAN_R5G6B5,
+    PIXMAN_B5G6R5,
+    PIXMAN_A1R5G5B5,
+    PIXMAN_X1R5G5B5,
+    PIXMAN_A1B5G5R5,
+    PIXMAN_X1B5G5R5,
+    PIXMAN_A4R4G4B4,
+    PIXMAN_X4R4G4B4,
+    PIXMAN_A4B4G4R4,
+    PIXMAN_X4B4G4R4,
+    PIXMAN_R3G3B2,
+    PIXMAN_B2G3R3,
+];
+
+/// Channels of a packed format, from the most significant bits
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Channels {
+    names: [u8; 4],
+    bits: [u8; 4],
+    len: usize,
+}
+
+impl Channels {
+    /// Read the channels from a name like `XRGB2101010`
+    fn from_name(name: &str) -> Option<Self> {
+        let split = name.find(|c: char| c.is_ascii_digit())?;
+        let (letters, digits) = name.split_at(split);
+
+        if letters.is_empty()
+            || letters.len() > 4
+            || !letters.bytes().all(|c| b"ARGBX".contains(&c))
+            || !digits.bytes().all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let mut channels = Channels {
+            names: [0; 4],
+            bits: [0; 4],
+            len: letters.len(),
+        };
+        channels.names[..letters.len()].copy_from_slice(letters.as_bytes());
+
+        if split_bits(digits.as_bytes(), &mut channels.bits[..letters.len()], 0) {
+            Some(channels)
+        } else {
+            None
+        }
+    }
+
+    fn bits_of(&self, name: u8) -> Option<u32> {
+        self.names[..self.len]
+            .iter()
+            .position(|&n| n == name)
+            .map(|index| u32::from(self.bits[index]))
+    }
+
+    fn order(&self) -> &[u8] {
+        &self.names[..self.len]
+    }
+}
+
+/// Split `digits` into one channel size per element of `bits`, each 1 to 16 bits, with the total
+/// a whole number of bytes.
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+fn split_bits(digits: &[u8], bits: &mut [u8], total: u32) -> bool {
+    match bits.split_first_mut() {
+        None => digits.is_empty() && total % 8 == 0,
+        Some((first, rest)) => (1..=digits.len().min(2)).any(|len| {
+            let value = digits[..len]
+                .iter()
+                .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'));
+            if !(1..=16).contains(&value) {
+                return false;
+            }
+
+            *first = value as u8;
+            split_bits(&digits[len..], rest, total + value)
+        }),
+    }
+}
+
+impl DrmFourcc {
+    /// Get the pixman format code with the same layout
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// # const PIXMAN_X8R8G8B8: u32 = 0x2002_0888;
+    /// # const PIXMAN_A2B10G10R10: u32 = 0x2003_2aaa;
+    /// assert_eq!(DrmFourcc::Xrgb8888.pixman_format_code(), Some(PIXMAN_X8R8G8B8));
+    /// assert_eq!(DrmFourcc::Abgr2101010.pixman_format_code(), Some(PIXMAN_A2B10G10R10));
+    /// assert_eq!(DrmFourcc::Rgba5551.pixman_format_code(), None);
+    /// ```
+    pub fn pixman_format_code(&self) -> Option<u32> {
+        match self {
+            DrmFourcc::Yuyv => return Some(PIXMAN_YUY2),
+            DrmFourcc::Yvu420 => return Some(PIXMAN_YV12),
+            _ => {}
+        }
+
+        let channels = Channels::from_name(self.short_name())?;
+        // Channel sizes are 4 bit fields of the code.
+        if channels.bits.iter().any(|&bits| bits > 15) {
+            return None;
+        }
+
+        let bpp: u32 = channels.bits[..channels.len]
+            .iter()
+            .map(|&b| u32::from(b))
+            .sum();
+        let alpha = channels.bits_of(b'A').unwrap_or(0);
+
+        let ty = match channels.order() {
+            b"ARGB" | b"XRGB" | b"RGB" => PIXMAN_TYPE_ARGB,
+            b"ABGR" | b"XBGR" | b"BGR" => PIXMAN_TYPE_ABGR,
+            b"BGRA" | b"BGRX" => PIXMAN_TYPE_BGRA,
+            b"RGBA" | b"RGBX" => PIXMAN_TYPE_RGBA,
+            _ => return None,
+        };
+
+        let code = pixman_format(
+            bpp,
+            ty,
+            alpha,
+            channels.bits_of(b'R')?,
+            channels.bits_of(b'G')?,
+            channels.bits_of(b'B')?,
+        );
+
+        if PIXMAN_RGB_FORMATS.contains(&code) {
+            Some(code)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_channels() {
+        let channels = Channels::from_name("XRGB2101010").unwrap();
+        assert_eq!(channels.order(), b"XRGB");
+        assert_eq!(channels.bits, [2, 10, 10, 10]);
+
+        let channels = Channels::from_name("ABGR16161616").unwrap();
+        assert_eq!(channels.bits, [16, 16, 16, 16]);
+
+        assert_eq!(Channels::from_name("RGB565_A8"), None);
+        assert_eq!(Channels::from_name("NV12"), None);
+        assert_eq!(Channels::from_name("C8"), None);
+    }
+
+    #[test]
+    fn every_rgb_format_is_reachable() {
+        let codes = [
+            DrmFourcc::Argb8888,
+            DrmFourcc::Xrgb8888,
+            DrmFourcc::Abgr8888,
+            DrmFourcc::Xbgr8888,
+            DrmFourcc::Bgra8888,
+            DrmFourcc::Bgrx8888,
+            DrmFourcc::Rgba8888,
+            DrmFourcc::Rgbx8888,
+            DrmFourcc::Xrgb2101010,
+            DrmFourcc::Argb2101010,
+            DrmFourcc::Xbgr2101010,
+            DrmFourcc::Abgr2101010,
+            DrmFourcc::Rgb888,
+            DrmFourcc::Bgr888,
+            DrmFourcc::Rgb565,
+            DrmFourcc::Bgr565,
+            DrmFourcc::Argb1555,
+            DrmFourcc::Xrgb1555,
+            DrmFourcc::Abgr1555,
+            DrmFourcc::Xbgr1555,
+            DrmFourcc::Argb4444,
+            DrmFourcc::Xrgb4444,
+            DrmFourcc::Abgr4444,
+            DrmFourcc::Xbgr4444,
+            DrmFourcc::Rgb332,
+            DrmFourcc::Bgr233,
+        ];
+
+        for (&code, &expected) in codes.iter().zip(PIXMAN_RGB_FORMATS) {
+            assert_eq!(code.pixman_format_code(), Some(expected), "{:?}", code);
+        }
+    }
+}
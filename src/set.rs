@@ -0,0 +1,296 @@
+//! A set of [`DrmFormat`]s grouped by fourcc, for negotiating formats between clients, renderers
+//! and planes.
+use std::collections::HashMap;
+use std::iter::FromIterator;
+use std::vec::Vec;
+
+use crate::{DrmFormat, DrmFourcc, DrmModifier};
+
+/// A set of format and modifier pairs, keyed by [`DrmFourcc`].
+///
+/// The set remembers the order in which codes and modifiers were first inserted and treats it as
+/// an order of preference: iteration, and the result of set operations, follow it.
+///
+/// ```
+/// # use drm_fourcc::{DrmFormat, DrmFormatSet, DrmFourcc, DrmModifier};
+/// let renderer: DrmFormatSet = [
+///     DrmFormat { code: DrmFourcc::Argb8888, modifier: DrmModifier::I915_y_tiled },
+///     DrmFormat { code: DrmFourcc::Argb8888, modifier: DrmModifier::Linear },
+///     DrmFormat { code: DrmFourcc::Xrgb8888, modifier: DrmModifier::Linear },
+/// ]
+/// .iter()
+/// .copied()
+/// .collect();
+/// let plane: DrmFormatSet = [
+///     DrmFormat { code: DrmFourcc::Xrgb8888, modifier: DrmModifier::Linear },
+///     DrmFormat { code: DrmFourcc::Argb8888, modifier: DrmModifier::Linear },
+///     DrmFormat { code: DrmFourcc::Argb8888, modifier: DrmModifier::I915_y_tiled },
+/// ]
+/// .iter()
+/// .copied()
+/// .collect();
+///
+/// let shared = renderer.intersection(&plane);
+/// assert_eq!(
+///     shared.iter().next(),
+///     Some(DrmFormat { code: DrmFourcc::Argb8888, modifier: DrmModifier::I915_y_tiled })
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DrmFormatSet {
+    /// Codes in order of preference
+    codes: Vec<DrmFourcc>,
+    /// Modifiers of every code, in order of preference
+    modifiers: HashMap<DrmFourcc, Vec<DrmModifier>>,
+}
+
+impl DrmFormatSet {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a format, with lower preference than everything already in the set.
+    ///
+    /// Returns `false` if the format was already present, in which case its preference is
+    /// unchanged.
+    pub fn insert(&mut self, format: DrmFormat) -> bool {
+        let codes = &mut self.codes;
+        let modifiers = self.modifiers.entry(format.code).or_insert_with(|| {
+            codes.push(format.code);
+            Vec::new()
+        });
+
+        if modifiers.contains(&format.modifier) {
+            false
+        } else {
+            modifiers.push(format.modifier);
+            true
+        }
+    }
+
+    /// Remove a format, returning whether it was present
+    pub fn remove(&mut self, format: &DrmFormat) -> bool {
+        let modifiers = match self.modifiers.get_mut(&format.code) {
+            Some(modifiers) => modifiers,
+            None => return false,
+        };
+        let index = match modifiers.iter().position(|m| *m == format.modifier) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        modifiers.remove(index);
+        if modifiers.is_empty() {
+            self.modifiers.remove(&format.code);
+            self.codes.retain(|code| *code != format.code);
+        }
+        true
+    }
+
+    /// Whether the set contains `format`
+    pub fn contains(&self, format: &DrmFormat) -> bool {
+        self.modifiers(format.code).contains(&format.modifier)
+    }
+
+    /// Whether `code` can be used with the linear modifier
+    pub fn contains_linear(&self, code: DrmFourcc) -> bool {
+        self.modifiers(code).contains(&DrmModifier::Linear)
+    }
+
+    /// The modifiers `code` can be used with, in order of preference
+    pub fn modifiers(&self, code: DrmFourcc) -> &[DrmModifier] {
+        self.modifiers.get(&code).map_or(&[], Vec::as_slice)
+    }
+
+    /// The codes in the set, in order of preference
+    pub fn codes(&self) -> &[DrmFourcc] {
+        &self.codes
+    }
+
+    /// Codes that can only be used with an implicit modifier ([`DrmModifier::Invalid`]).
+    ///
+    /// These are what legacy clients without modifier support can allocate.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFormat, DrmFormatSet, DrmFourcc, DrmModifier};
+    /// let mut set = DrmFormatSet::new();
+    /// set.insert(DrmFormat { code: DrmFourcc::Nv12, modifier: DrmModifier::Invalid });
+    /// set.insert(DrmFormat { code: DrmFourcc::Xrgb8888, modifier: DrmModifier::Invalid });
+    /// set.insert(DrmFormat { code: DrmFourcc::Xrgb8888, modifier: DrmModifier::Linear });
+    ///
+    /// assert_eq!(set.implicit_modifier_only().collect::<Vec<_>>(), [DrmFourcc::Nv12]);
+    /// ```
+    pub fn implicit_modifier_only(&self) -> impl Iterator<Item = DrmFourcc> + '_ {
+        self.codes
+            .iter()
+            .copied()
+            .filter(move |&code| self.modifiers(code) == [DrmModifier::Invalid])
+    }
+
+    /// Formats present in both sets, in this set's order of preference
+    pub fn intersection(&self, other: &DrmFormatSet) -> DrmFormatSet {
+        self.iter()
+            .filter(|format| other.contains(format))
+            .collect()
+    }
+
+    /// Formats present in either set, this set's formats taking preference
+    pub fn union(&self, other: &DrmFormatSet) -> DrmFormatSet {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    /// Number of format and modifier pairs
+    pub fn len(&self) -> usize {
+        self.modifiers.values().map(Vec::len).sum()
+    }
+
+    /// Whether the set contains no formats
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// Iterate over the formats in order of preference: by code, then by modifier
+    pub fn iter(&self) -> DrmFormatSetIter<'_> {
+        DrmFormatSetIter {
+            set: self,
+            code: 0,
+            modifier: 0,
+        }
+    }
+}
+
+impl PartialEq for DrmFormatSet {
+    /// Sets are equal if they contain the same formats, regardless of order
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|format| other.contains(&format))
+    }
+}
+
+impl Eq for DrmFormatSet {}
+
+impl FromIterator<DrmFormat> for DrmFormatSet {
+    fn from_iter<I: IntoIterator<Item = DrmFormat>>(iter: I) -> Self {
+        let mut set = DrmFormatSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<DrmFormat> for DrmFormatSet {
+    fn extend<I: IntoIterator<Item = DrmFormat>>(&mut self, iter: I) {
+        for format in iter {
+            self.insert(format);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a DrmFormatSet {
+    type Item = DrmFormat;
+    type IntoIter = DrmFormatSetIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the formats of a [`DrmFormatSet`] in order of preference
+#[derive(Debug, Clone)]
+pub struct DrmFormatSetIter<'a> {
+    set: &'a DrmFormatSet,
+    /// Index into `set.codes`
+    code: usize,
+    /// Index into the modifiers of the current code
+    modifier: usize,
+}
+
+impl Iterator for DrmFormatSetIter<'_> {
+    type Item = DrmFormat;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&code) = self.set.codes.get(self.code) {
+            if let Some(&modifier) = self.set.modifiers(code).get(self.modifier) {
+                self.modifier += 1;
+                return Some(DrmFormat { code, modifier });
+            }
+
+            self.code += 1;
+            self.modifier = 0;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(code: DrmFourcc, modifier: DrmModifier) -> DrmFormat {
+        DrmFormat { code, modifier }
+    }
+
+    #[test]
+    fn iterates_in_insertion_order() {
+        let formats = [
+            format(DrmFourcc::Xrgb8888, DrmModifier::I915_x_tiled),
+            format(DrmFourcc::Nv12, DrmModifier::Linear),
+            format(DrmFourcc::Xrgb8888, DrmModifier::Linear),
+            format(DrmFourcc::Nv12, DrmModifier::Linear),
+        ];
+        let set: DrmFormatSet = formats.iter().copied().collect();
+
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.codes(), [DrmFourcc::Xrgb8888, DrmFourcc::Nv12]);
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [formats[0], formats[2], formats[1]]
+        );
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: DrmFormatSet = [
+            format(DrmFourcc::Xrgb8888, DrmModifier::Linear),
+            format(DrmFourcc::Argb8888, DrmModifier::Linear),
+        ]
+        .iter()
+        .copied()
+        .collect();
+        let b: DrmFormatSet = [
+            format(DrmFourcc::Argb8888, DrmModifier::I915_x_tiled),
+            format(DrmFourcc::Argb8888, DrmModifier::Linear),
+        ]
+        .iter()
+        .copied()
+        .collect();
+
+        let intersection = a.intersection(&b);
+        assert_eq!(
+            intersection.iter().collect::<Vec<_>>(),
+            [format(DrmFourcc::Argb8888, DrmModifier::Linear)]
+        );
+        assert!(!intersection.contains_linear(DrmFourcc::Xrgb8888));
+
+        let union = a.union(&b);
+        assert_eq!(union.len(), 3);
+        assert_eq!(union, b.union(&a));
+        assert_eq!(
+            union.modifiers(DrmFourcc::Argb8888),
+            [DrmModifier::Linear, DrmModifier::I915_x_tiled]
+        );
+    }
+
+    #[test]
+    fn remove_drops_empty_codes() {
+        let mut set = DrmFormatSet::new();
+        let linear = format(DrmFourcc::Xrgb8888, DrmModifier::Linear);
+
+        assert!(set.insert(linear));
+        assert!(!set.insert(linear));
+        assert!(set.remove(&linear));
+        assert!(!set.remove(&linear));
+        assert!(set.is_empty());
+        assert!(set.codes().is_empty());
+    }
+}
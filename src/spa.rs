@@ -0,0 +1,241 @@
+//! Mapping to the PipeWire SPA video formats (`enum spa_video_format` in
+//! `spa/param/video/raw.h`), and the modifier choice of `SPA_FORMAT_VIDEO_modifier`.
+//!
+//! Like GStreamer, SPA names packed formats by memory byte order: `SPA_VIDEO_FORMAT_BGRx` is
+//! [`DrmFourcc::Xrgb8888`].
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::{DrmFormat, DrmFourcc, DrmModifier};
+
+const SPA_VIDEO_FORMAT_I420: u32 = 2;
+const SPA_VIDEO_FORMAT_YV12: u32 = 3;
+const SPA_VIDEO_FORMAT_YUY2: u32 = 4;
+const SPA_VIDEO_FORMAT_UYVY: u32 = 5;
+const SPA_VIDEO_FORMAT_RGBX: u32 = 7;
+const SPA_VIDEO_FORMAT_BGRX: u32 = 8;
+const SPA_VIDEO_FORMAT_XRGB: u32 = 9;
+const SPA_VIDEO_FORMAT_XBGR: u32 = 10;
+const SPA_VIDEO_FORMAT_RGBA: u32 = 11;
+const SPA_VIDEO_FORMAT_BGRA: u32 = 12;
+const SPA_VIDEO_FORMAT_ARGB: u32 = 13;
+const SPA_VIDEO_FORMAT_ABGR: u32 = 14;
+const SPA_VIDEO_FORMAT_RGB: u32 = 15;
+const SPA_VIDEO_FORMAT_BGR: u32 = 16;
+const SPA_VIDEO_FORMAT_Y41B: u32 = 17;
+const SPA_VIDEO_FORMAT_Y42B: u32 = 18;
+const SPA_VIDEO_FORMAT_YVYU: u32 = 19;
+const SPA_VIDEO_FORMAT_Y444: u32 = 20;
+const SPA_VIDEO_FORMAT_NV12: u32 = 23;
+const SPA_VIDEO_FORMAT_NV21: u32 = 24;
+const SPA_VIDEO_FORMAT_GRAY8: u32 = 25;
+const SPA_VIDEO_FORMAT_GRAY16_LE: u32 = 27;
+const SPA_VIDEO_FORMAT_RGB16: u32 = 29;
+const SPA_VIDEO_FORMAT_BGR16: u32 = 30;
+const SPA_VIDEO_FORMAT_RGB15: u32 = 31;
+const SPA_VIDEO_FORMAT_BGR15: u32 = 32;
+const SPA_VIDEO_FORMAT_YUV9: u32 = 36;
+const SPA_VIDEO_FORMAT_YVU9: u32 = 37;
+const SPA_VIDEO_FORMAT_NV16: u32 = 51;
+const SPA_VIDEO_FORMAT_NV24: u32 = 52;
+const SPA_VIDEO_FORMAT_NV61: u32 = 60;
+const SPA_VIDEO_FORMAT_P010_10LE: u32 = 62;
+const SPA_VIDEO_FORMAT_VYUY: u32 = 64;
+const SPA_VIDEO_FORMAT_RGBA_F16: u32 = 78;
+const SPA_VIDEO_FORMAT_XRGB_210LE: u32 = 80;
+const SPA_VIDEO_FORMAT_XBGR_210LE: u32 = 81;
+const SPA_VIDEO_FORMAT_RGBX_102LE: u32 = 82;
+const SPA_VIDEO_FORMAT_BGRX_102LE: u32 = 83;
+const SPA_VIDEO_FORMAT_ARGB_210LE: u32 = 84;
+const SPA_VIDEO_FORMAT_ABGR_210LE: u32 = 85;
+const SPA_VIDEO_FORMAT_RGBA_102LE: u32 = 86;
+const SPA_VIDEO_FORMAT_BGRA_102LE: u32 = 87;
+
+const SPA_VIDEO_FORMATS: &[(DrmFourcc, u32)] = &[
+    (DrmFourcc::Xbgr8888, SPA_VIDEO_FORMAT_RGBX),
+    (DrmFourcc::Xrgb8888, SPA_VIDEO_FORMAT_BGRX),
+    (DrmFourcc::Bgrx8888, SPA_VIDEO_FORMAT_XRGB),
+    (DrmFourcc::Rgbx8888, SPA_VIDEO_FORMAT_XBGR),
+    (DrmFourcc::Abgr8888, SPA_VIDEO_FORMAT_RGBA),
+    (DrmFourcc::Argb8888, SPA_VIDEO_FORMAT_BGRA),
+    (DrmFourcc::Bgra8888, SPA_VIDEO_FORMAT_ARGB),
+    (DrmFourcc::Rgba8888, SPA_VIDEO_FORMAT_ABGR),
+    (DrmFourcc::Bgr888, SPA_VIDEO_FORMAT_RGB),
+    (DrmFourcc::Rgb888, SPA_VIDEO_FORMAT_BGR),
+    (DrmFourcc::Rgb565, SPA_VIDEO_FORMAT_RGB16),
+    (DrmFourcc::Bgr565, SPA_VIDEO_FORMAT_BGR16),
+    (DrmFourcc::Xrgb1555, SPA_VIDEO_FORMAT_RGB15),
+    (DrmFourcc::Xbgr1555, SPA_VIDEO_FORMAT_BGR15),
+    (DrmFourcc::Xrgb2101010, SPA_VIDEO_FORMAT_XRGB_210LE),
+    (DrmFourcc::Xbgr2101010, SPA_VIDEO_FORMAT_XBGR_210LE),
+    (DrmFourcc::Rgbx1010102, SPA_VIDEO_FORMAT_RGBX_102LE),
+    (DrmFourcc::Bgrx1010102, SPA_VIDEO_FORMAT_BGRX_102LE),
+    (DrmFourcc::Argb2101010, SPA_VIDEO_FORMAT_ARGB_210LE),
+    (DrmFourcc::Abgr2101010, SPA_VIDEO_FORMAT_ABGR_210LE),
+    (DrmFourcc::Rgba1010102, SPA_VIDEO_FORMAT_RGBA_102LE),
+    (DrmFourcc::Bgra1010102, SPA_VIDEO_FORMAT_BGRA_102LE),
+    (DrmFourcc::Abgr16161616f, SPA_VIDEO_FORMAT_RGBA_F16),
+    (DrmFourcc::R8, SPA_VIDEO_FORMAT_GRAY8),
+    (DrmFourcc::R16, SPA_VIDEO_FORMAT_GRAY16_LE),
+    (DrmFourcc::Yuyv, SPA_VIDEO_FORMAT_YUY2),
+    (DrmFourcc::Yvyu, SPA_VIDEO_FORMAT_YVYU),
+    (DrmFourcc::Uyvy, SPA_VIDEO_FORMAT_UYVY),
+    (DrmFourcc::Vyuy, SPA_VIDEO_FORMAT_VYUY),
+    (DrmFourcc::Nv12, SPA_VIDEO_FORMAT_NV12),
+    (DrmFourcc::Nv21, SPA_VIDEO_FORMAT_NV21),
+    (DrmFourcc::Nv16, SPA_VIDEO_FORMAT_NV16),
+    (DrmFourcc::Nv61, SPA_VIDEO_FORMAT_NV61),
+    (DrmFourcc::Nv24, SPA_VIDEO_FORMAT_NV24),
+    (DrmFourcc::P010, SPA_VIDEO_FORMAT_P010_10LE),
+    (DrmFourcc::Yuv410, SPA_VIDEO_FORMAT_YUV9),
+    (DrmFourcc::Yvu410, SPA_VIDEO_FORMAT_YVU9),
+    (DrmFourcc::Yuv411, SPA_VIDEO_FORMAT_Y41B),
+    (DrmFourcc::Yuv420, SPA_VIDEO_FORMAT_I420),
+    (DrmFourcc::Yvu420, SPA_VIDEO_FORMAT_YV12),
+    (DrmFourcc::Yuv422, SPA_VIDEO_FORMAT_Y42B),
+    (DrmFourcc::Yuv444, SPA_VIDEO_FORMAT_Y444),
+];
+
+impl DrmFourcc {
+    /// Get the `spa_video_format` with the same layout
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// # const SPA_VIDEO_FORMAT_BGRX: u32 = 8;
+    /// assert_eq!(DrmFourcc::Xrgb8888.to_spa_video_format(), Some(SPA_VIDEO_FORMAT_BGRX));
+    /// assert_eq!(DrmFourcc::C8.to_spa_video_format(), None);
+    /// ```
+    pub fn to_spa_video_format(&self) -> Option<u32> {
+        SPA_VIDEO_FORMATS
+            .iter()
+            .find(|(code, _)| code == self)
+            .map(|&(_, format)| format)
+    }
+
+    /// Look up a format by its `spa_video_format`
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// # const SPA_VIDEO_FORMAT_NV12: u32 = 23;
+    /// assert_eq!(
+    ///     DrmFourcc::from_spa_video_format(SPA_VIDEO_FORMAT_NV12),
+    ///     Some(DrmFourcc::Nv12)
+    /// );
+    /// assert_eq!(DrmFourcc::from_spa_video_format(0), None);
+    /// ```
+    pub fn from_spa_video_format(format: u32) -> Option<Self> {
+        SPA_VIDEO_FORMATS
+            .iter()
+            .find(|&&(_, spa_format)| spa_format == format)
+            .map(|&(code, _)| code)
+    }
+}
+
+/// Build the values of the `SPA_CHOICE_Enum` for `SPA_FORMAT_VIDEO_modifier`.
+///
+/// An enum choice starts with its default value followed by every alternative, so the first,
+/// most preferred, modifier appears twice. Modifiers are `Long`s in SPA pods.
+///
+/// ```
+/// # use drm_fourcc::DrmModifier;
+/// # use drm_fourcc::spa_modifier_choice;
+/// let choice = spa_modifier_choice([DrmModifier::I915_x_tiled, DrmModifier::Linear].iter().copied());
+/// assert_eq!(choice, [0x0100000000000001, 0x0100000000000001, 0]);
+/// ```
+#[cfg(feature = "std")]
+pub fn spa_modifier_choice<I>(modifiers: I) -> Vec<i64>
+where
+    I: IntoIterator<Item = DrmModifier>,
+{
+    let mut values: Vec<i64> = modifiers
+        .into_iter()
+        .map(|modifier| u64::from(modifier) as i64)
+        .collect();
+
+    if let Some(&default) = values.first() {
+        values.insert(0, default);
+    }
+    values
+}
+
+/// The modifiers offered by the values of a `SPA_FORMAT_VIDEO_modifier` choice.
+///
+/// A single value is a fixated modifier. Otherwise the first value is the enum's default, which is
+/// skipped as it is repeated among the alternatives.
+///
+/// ```
+/// # use drm_fourcc::DrmModifier;
+/// # use drm_fourcc::parse_spa_modifier_choice;
+/// let modifiers: Vec<_> = parse_spa_modifier_choice(&[0, 0, 0x0100000000000002]).collect();
+/// assert_eq!(modifiers, [DrmModifier::Linear, DrmModifier::I915_y_tiled]);
+/// ```
+pub fn parse_spa_modifier_choice(values: &[i64]) -> impl Iterator<Item = DrmModifier> + '_ {
+    let alternatives = if values.len() > 1 {
+        &values[1..]
+    } else {
+        values
+    };
+
+    alternatives
+        .iter()
+        .map(|&value| DrmModifier::from(value as u64))
+}
+
+/// The formats described by an SPA video format and the values of its modifier choice.
+///
+/// Returns `None` if the video format has no DRM equivalent.
+///
+/// ```
+/// # use drm_fourcc::{DrmFormat, DrmFourcc, DrmModifier};
+/// # use drm_fourcc::formats_from_spa_choice;
+/// # const SPA_VIDEO_FORMAT_BGRA: u32 = 12;
+/// let mut formats = formats_from_spa_choice(SPA_VIDEO_FORMAT_BGRA, &[0, 0]).unwrap();
+/// assert_eq!(
+///     formats.next(),
+///     Some(DrmFormat { code: DrmFourcc::Argb8888, modifier: DrmModifier::Linear })
+/// );
+/// assert_eq!(formats.next(), None);
+/// ```
+pub fn formats_from_spa_choice(
+    video_format: u32,
+    values: &[i64],
+) -> Option<impl Iterator<Item = DrmFormat> + '_> {
+    let code = DrmFourcc::from_spa_video_format(video_format)?;
+
+    Some(parse_spa_modifier_choice(values).map(move |modifier| DrmFormat { code, modifier }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn video_formats_are_unique() {
+        for &(code, format) in SPA_VIDEO_FORMATS {
+            assert_eq!(code.to_spa_video_format(), Some(format));
+            assert_eq!(DrmFourcc::from_spa_video_format(format), Some(code));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn modifier_choice_round_trips() {
+        let modifiers = [
+            DrmModifier::Invalid,
+            DrmModifier::Linear,
+            DrmModifier::I915_y_tiled,
+        ];
+        let choice = spa_modifier_choice(modifiers.iter().copied());
+
+        assert_eq!(choice[0], (1 << 56) - 1);
+        assert_eq!(
+            parse_spa_modifier_choice(&choice).collect::<Vec<_>>(),
+            modifiers
+        );
+        assert_eq!(
+            parse_spa_modifier_choice(&choice[1..2]).collect::<Vec<_>>(),
+            [DrmModifier::Invalid]
+        );
+        assert!(spa_modifier_choice(None).is_empty());
+    }
+}
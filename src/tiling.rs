@@ -0,0 +1,1108 @@
+//! Software conversion between linear and tiled buffers, for reading back tiled buffers on the
+//! CPU without the GPU that wrote them.
+//!
+//! Single plane formats whose blocks are one line tall are supported, as well as NV12 and NV21
+//! for the layouts of video decoders. Both the tiled and the linear buffer use the same `pitch`,
+//! which must be a whole number of tiles wide. Planes are stored back to back, and each plane of
+//! the tiled buffer is padded to a whole number of tile rows.
+//!
+//! The Broadcom layouts are built from 64 byte utiles whose size depends on the number of bytes
+//! per pixel. VC4 T tiles and V3D UIF blocks share utiles up to 4 bytes per pixel, but from 8
+//! bytes per pixel V3D utiles are 2 lines tall where VC4 ones stay 4 lines tall. VC4 has no 16
+//! bytes per pixel T format, so T tiling supports formats with 1, 2, 4 or 8 bytes per pixel and
+//! UIF also supports 16.
+//!
+//! The height of UIF columns and whether odd columns are swizzled aren't part of the modifier:
+//! [`untile`] and [`tile`] assume columns the height of the buffer without swizzling, and
+//! [`untile_uif`] and [`tile_uif`] take them explicitly.
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{DrmFourcc, DrmModifier, FormatInfo, NvidiaBlockLinear};
+
+/// Size of a single tile.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TileGeometry {
+    /// Width of a tile in bytes
+    pub width: u32,
+    /// Height of a tile in lines
+    pub height: u32,
+}
+
+/// Reasons a buffer can't be converted
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TilingError {
+    /// The modifier isn't one of the supported tiled layouts
+    UnsupportedModifier(DrmModifier),
+    /// The format has blocks spanning several lines, or more than one plane and the layout isn't
+    /// meant for NV12
+    UnsupportedFormat(DrmFourcc),
+    /// The pitch isn't a whole number of tiles, or is too small for the width
+    InvalidPitch(u32),
+    /// The source or destination buffer is too small for the given dimensions
+    BufferTooSmall,
+    /// The buffer size doesn't fit in an usize
+    Overflow,
+    /// The UIF column height, in blocks, is smaller than the buffer or isn't a multiple of 32
+    /// with XOR
+    InvalidColumnHeight(u32),
+}
+
+impl Display for TilingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TilingError {}
+
+/// Get the size of the tiles of the first plane of a buffer in the given format and layout.
+///
+/// The pitch of the tiled buffer must be a multiple of the width, and its height is rounded up to
+/// a multiple of the height. For UIF this is a line of blocks across a column, and for
+/// `Samsung_64_32_tile` a pair of tiles.
+///
+/// ```
+/// # use drm_fourcc::{DrmFourcc, DrmModifier};
+/// # use drm_fourcc::{tile_geometry, TileGeometry};
+/// assert_eq!(
+///     tile_geometry(DrmFourcc::Xrgb8888, DrmModifier::I915_y_tiled),
+///     Ok(TileGeometry { width: 128, height: 32 })
+/// );
+/// ```
+pub fn tile_geometry(
+    format: DrmFourcc,
+    modifier: DrmModifier,
+) -> Result<TileGeometry, TilingError> {
+    Tiling::new(format, modifier, 0).map(|(tiling, _)| tiling.geometry())
+}
+
+/// Convert a tiled buffer to linear.
+///
+/// `src` holds the tiled buffer and `dst` receives the linear one, both `pitch` bytes per line.
+///
+/// ```
+/// # use drm_fourcc::{DrmFourcc, DrmModifier};
+/// # use drm_fourcc::untile;
+/// let tiled = vec![0; 512 * 8];
+/// let mut linear = vec![0; 512 * 4];
+/// untile(&tiled, &mut linear, DrmFourcc::Xrgb8888, DrmModifier::I915_x_tiled, 100, 4, 512)
+///     .unwrap();
+/// ```
+pub fn untile(
+    src: &[u8],
+    dst: &mut [u8],
+    format: DrmFourcc,
+    modifier: DrmModifier,
+    width: u32,
+    height: u32,
+    pitch: u32,
+) -> Result<(), TilingError> {
+    Surface::new(format, modifier, None, width, height, pitch)?.untile(src, dst)
+}
+
+/// Convert a linear buffer to tiled, the inverse of [`untile`].
+///
+/// `src` holds the linear buffer and `dst` receives the tiled one, both `pitch` bytes per line.
+/// Padding bytes of `dst` outside of the image are left untouched.
+pub fn tile(
+    src: &[u8],
+    dst: &mut [u8],
+    format: DrmFourcc,
+    modifier: DrmModifier,
+    width: u32,
+    height: u32,
+    pitch: u32,
+) -> Result<(), TilingError> {
+    Surface::new(format, modifier, None, width, height, pitch)?.tile(src, dst)
+}
+
+/// Layout of a [`DrmModifier::Broadcom_uif`] buffer beyond what the modifier describes.
+///
+/// The driver picks both when allocating the buffer, V3D for example pads columns to avoid page
+/// cache conflicts and uses XOR when the padded height is a multiple of the page cache.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct UifLayout {
+    /// Height of the columns in blocks, at least the height of the buffer
+    pub block_rows: u32,
+    /// Whether odd columns swap every other group of 16 block rows
+    pub xor: bool,
+}
+
+/// Convert a UIF buffer with the given column layout to linear, otherwise like [`untile`].
+///
+/// ```
+/// # use drm_fourcc::DrmFourcc;
+/// # use drm_fourcc::{untile_uif, UifLayout};
+/// // With 32 bit pixels UIF blocks are 8 lines tall and columns 128 bytes wide.
+/// let layout = UifLayout { block_rows: 32, xor: true };
+/// let tiled = vec![0; 256 * 32 * 8];
+/// let mut linear = vec![0; 256 * 100];
+/// untile_uif(&tiled, &mut linear, DrmFourcc::Xrgb8888, layout, 64, 100, 256).unwrap();
+/// ```
+pub fn untile_uif(
+    src: &[u8],
+    dst: &mut [u8],
+    format: DrmFourcc,
+    layout: UifLayout,
+    width: u32,
+    height: u32,
+    pitch: u32,
+) -> Result<(), TilingError> {
+    let modifier = DrmModifier::Broadcom_uif;
+    Surface::new(format, modifier, Some(layout), width, height, pitch)?.untile(src, dst)
+}
+
+/// Convert a linear buffer to UIF with the given column layout, the inverse of [`untile_uif`].
+pub fn tile_uif(
+    src: &[u8],
+    dst: &mut [u8],
+    format: DrmFourcc,
+    layout: UifLayout,
+    width: u32,
+    height: u32,
+    pitch: u32,
+) -> Result<(), TilingError> {
+    let modifier = DrmModifier::Broadcom_uif;
+    Surface::new(format, modifier, Some(layout), width, height, pitch)?.tile(src, dst)
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Tiling {
+    /// 4KiB tiles of 8 lines of 512 bytes, stored line by line
+    IntelX,
+    /// 4KiB tiles of 32 lines of 128 bytes, stored as 8 columns of 16 bytes wide OWords
+    IntelY,
+    /// 4KiB tiles of 2x2 1KiB subtiles of 4x4 64 byte utiles, with every other row of tiles
+    /// stored right to left
+    Vc4T { utile: Utile },
+    /// 256 byte blocks of 2x2 utiles, stored in columns four blocks wide running the whole padded
+    /// height of the buffer. With `xor`, odd columns swap every other group of 16 block rows.
+    Uif { utile: Utile, xor: bool },
+    /// Blocks one 64 byte wide GOB across and `gobs` GOBs tall, stored GOB by GOB. GOBs are 8
+    /// lines tall and made of 16 bytes by 2 lines sectors.
+    Nvidia16Bx2 { gobs: usize },
+    /// 2KiB tiles of 32 lines of 64 bytes, stored line by line. Each 2x2 group of tiles is stored
+    /// in Z order, alternating with mirrored Z order along a row of groups, except a lone last row
+    /// of tiles which is stored left to right.
+    SamsungZ,
+    /// Tiles of `height` lines of `width` bytes, stored line by line and left to right
+    RowMajor { width: usize, height: usize },
+    /// Tiles of 4x4 pixels stored line by line. Super tiles are 64x64 pixels, made of 8x4 groups
+    /// of 2x4 tiles, all stored left to right. Split layouts store every other tile in the second
+    /// half of the buffer, for the second pixel pipe.
+    Vivante {
+        cpp: usize,
+        super_tiled: bool,
+        split: bool,
+    },
+}
+
+/// Width in bytes and height in lines of the 64 byte utiles of the Broadcom layouts, which depend
+/// on the number of bytes per pixel.
+#[derive(Debug, Copy, Clone)]
+struct Utile {
+    width: usize,
+    height: usize,
+}
+
+impl Utile {
+    const fn new(width: usize, height: usize) -> Self {
+        Utile { width, height }
+    }
+
+    /// Utiles of VC4, which has no 16 bytes per pixel formats
+    fn vc4(cpp: u32) -> Option<Self> {
+        match cpp {
+            1 => Some(Utile::new(8, 8)),
+            2 | 4 | 8 => Some(Utile::new(16, 4)),
+            _ => None,
+        }
+    }
+
+    /// Utiles of V3D, which are 2 lines tall from 8 bytes per pixel
+    fn v3d(cpp: u32) -> Option<Self> {
+        match cpp {
+            1 => Some(Utile::new(8, 8)),
+            2 | 4 => Some(Utile::new(16, 4)),
+            8 | 16 => Some(Utile::new(32, 2)),
+            _ => None,
+        }
+    }
+
+    /// Offset within the utile of byte `x` of line `y` of the utile.
+    fn offset(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+}
+
+impl Tiling {
+    /// The layout of the given plane of a `format` buffer with `modifier`, and the format's
+    /// layout.
+    fn new(
+        format: DrmFourcc,
+        modifier: DrmModifier,
+        plane: usize,
+    ) -> Result<(Self, FormatInfo), TilingError> {
+        let unsupported = TilingError::UnsupportedFormat(format);
+        let info = format.info().ok_or(unsupported)?;
+        let nv12 = matches!(format, DrmFourcc::Nv12 | DrmFourcc::Nv21);
+        let require_single_plane = || {
+            if info.num_planes == 1 && info.char_per_block[0] != 0 && info.block_height(0) == 1 {
+                Ok(())
+            } else {
+                Err(unsupported)
+            }
+        };
+        let require_nv12 = || if nv12 { Ok(()) } else { Err(unsupported) };
+        let utile = |utile: fn(u32) -> Option<Utile>| {
+            utile(info.char_per_block[0].into()).ok_or(unsupported)
+        };
+
+        let tiling = match modifier {
+            DrmModifier::I915_x_tiled => Tiling::IntelX,
+            DrmModifier::I915_y_tiled => Tiling::IntelY,
+            DrmModifier::Broadcom_vc4_t_tiled => Tiling::Vc4T {
+                utile: utile(Utile::vc4)?,
+            },
+            DrmModifier::Broadcom_uif => Tiling::Uif {
+                utile: utile(Utile::v3d)?,
+                xor: false,
+            },
+            DrmModifier::Samsung_64_32_tile => {
+                require_nv12()?;
+                Tiling::SamsungZ
+            }
+            // Samsung_16_16_tile shares its value with Generic_16_16_tile, so either variant can
+            // come out of a conversion from u64.
+            DrmModifier::Samsung_16_16_tile | DrmModifier::Generic_16_16_tile => {
+                require_nv12()?;
+                // Chroma tiles cover the same pixels as luma tiles.
+                let height = if plane == 0 { 16 } else { 16 / info.vsub };
+                Tiling::RowMajor {
+                    width: 16,
+                    height: height.into(),
+                }
+            }
+            DrmModifier::Vivante_tiled
+            | DrmModifier::Vivante_super_tiled
+            | DrmModifier::Vivante_split_tiled
+            | DrmModifier::Vivante_split_super_tiled => Tiling::Vivante {
+                cpp: info.char_per_block[0].into(),
+                super_tiled: matches!(
+                    modifier,
+                    DrmModifier::Vivante_super_tiled | DrmModifier::Vivante_split_super_tiled
+                ),
+                split: matches!(
+                    modifier,
+                    DrmModifier::Vivante_split_tiled | DrmModifier::Vivante_split_super_tiled
+                ),
+            },
+            DrmModifier::Allwinner_tiled => {
+                require_nv12()?;
+                Tiling::RowMajor {
+                    width: 32,
+                    height: 32,
+                }
+            }
+            _ => match modifier.nvidia_block_linear() {
+                Some(block_linear) if is_16bx2(&block_linear) => Tiling::Nvidia16Bx2 {
+                    gobs: block_linear.block_height_gobs() as usize,
+                },
+                _ => return Err(TilingError::UnsupportedModifier(modifier)),
+            },
+        };
+
+        if !matches!(tiling, Tiling::SamsungZ | Tiling::RowMajor { .. }) {
+            require_single_plane()?;
+        }
+        Ok((tiling, info))
+    }
+
+    /// The smallest unit the pitch and height of the tiled buffer must be a multiple of.
+    fn geometry(&self) -> TileGeometry {
+        let (width, height) = match *self {
+            Tiling::IntelX => (512, 8),
+            Tiling::IntelY => (128, 32),
+            Tiling::Vc4T { utile } => (utile.width * 8, utile.height * 8),
+            Tiling::Uif { utile, .. } => (utile.width * 2 * 4, utile.height * 2),
+            Tiling::Nvidia16Bx2 { gobs } => (64, gobs * 8),
+            Tiling::SamsungZ => (64 * 2, 32),
+            Tiling::RowMajor { width, height } => (width, height),
+            Tiling::Vivante {
+                cpp,
+                super_tiled: true,
+                ..
+            } => (64 * cpp, 64),
+            // Split buffers hold an even number of tiles, half for each pipe.
+            Tiling::Vivante { cpp, split, .. } => (4 * cpp, if split { 8 } else { 4 }),
+        };
+        TileGeometry {
+            width: width as u32,
+            height: height as u32,
+        }
+    }
+
+    /// Number of bytes, starting at a multiple of it, that are contiguous in both layouts.
+    fn span(&self) -> usize {
+        match *self {
+            Tiling::IntelX => 512,
+            Tiling::IntelY | Tiling::Nvidia16Bx2 { .. } => 16,
+            Tiling::Vc4T { utile } | Tiling::Uif { utile, .. } => utile.width,
+            Tiling::SamsungZ => 64,
+            Tiling::RowMajor { width, .. } => width,
+            Tiling::Vivante { cpp, .. } => 4 * cpp,
+        }
+    }
+
+    /// Offset in the tiled buffer of byte `x` of line `y`, for a buffer `pitch` bytes wide and
+    /// padded to `lines` lines.
+    fn offset(&self, x: usize, y: usize, pitch: usize, lines: usize) -> usize {
+        let geometry = self.geometry();
+        let (tile_width, tile_height) = (geometry.width as usize, geometry.height as usize);
+        let tiles_per_row = pitch / tile_width;
+        let (tile_x, tile_y) = (x / tile_width, y / tile_height);
+        let (x, y) = (x % tile_width, y % tile_height);
+
+        match *self {
+            Tiling::IntelX => (tile_y * tiles_per_row + tile_x) * 4096 + y * 512 + x,
+            Tiling::IntelY => {
+                (tile_y * tiles_per_row + tile_x) * 4096 + (x / 16) * 512 + y * 16 + x % 16
+            }
+            Tiling::Vc4T { utile } => {
+                let odd = tile_y % 2 == 1;
+                let tile_x = if odd {
+                    tiles_per_row - tile_x - 1
+                } else {
+                    tile_x
+                };
+
+                let (utile_x, utile_y) = (x / utile.width, y / utile.height);
+                let subtile = (utile_y / 4) * 2 + utile_x / 4;
+                let subtile = if odd {
+                    [2, 1, 3, 0][subtile]
+                } else {
+                    [0, 3, 1, 2][subtile]
+                };
+
+                (tile_y * tiles_per_row + tile_x) * 4096
+                    + subtile * 1024
+                    + ((utile_y % 4) * 4 + utile_x % 4) * 64
+                    + utile.offset(x % utile.width, y % utile.height)
+            }
+            Tiling::Uif { utile, xor } => {
+                // Tiles are lines of four blocks across a column.
+                let tile_y = if xor && tile_x % 2 == 1 {
+                    tile_y ^ 16
+                } else {
+                    tile_y
+                };
+                let block_width = utile.width * 2;
+                let blocks_per_column = lines / tile_height * 4;
+                let block = tile_x * blocks_per_column + tile_y * 4 + x / block_width;
+
+                let x = x % block_width;
+                let (utile_x, utile_y) = (x / utile.width, y / utile.height);
+                block * 256
+                    + (utile_y * 2 + utile_x) * 64
+                    + utile.offset(x % utile.width, y % utile.height)
+            }
+            Tiling::Nvidia16Bx2 { gobs } => {
+                (tile_y * tiles_per_row + tile_x) * 512 * gobs
+                    + (y / 8) * 512
+                    + (x / 32) * 256
+                    + (y % 8 / 2) * 64
+                    + (x % 32 / 16) * 32
+                    + (y % 2) * 16
+                    + x % 16
+            }
+            Tiling::SamsungZ => {
+                // Tiles are pairs of actual tiles, each group of four taking two tile rows.
+                let (tile_x, x) = (tile_x * 2 + x / 64, x % 64);
+                let (tiles_per_row, tile_rows) = (tiles_per_row * 2, lines / tile_height);
+
+                let mut tile = (tile_y & !1) * tiles_per_row + tile_x;
+                if tile_y % 2 == 1 {
+                    tile += (tile_x & !3) + 2;
+                } else if tile_rows % 2 == 0 || tile_y != tile_rows - 1 {
+                    tile += (tile_x + 2) & !3;
+                }
+                tile * 2048 + y * 64 + x
+            }
+            Tiling::RowMajor { width, height } => {
+                (tile_y * tiles_per_row + tile_x) * width * height + y * width + x
+            }
+            Tiling::Vivante {
+                cpp,
+                super_tiled,
+                split,
+            } => {
+                let (vivante_tile_width, vivante_tile_size) = (4 * cpp, 16 * cpp);
+                let (vivante_x, vivante_y) = (x / vivante_tile_width, y / 4);
+                let (x, y) = (x % vivante_tile_width, y % 4);
+
+                let vivante_tile = if super_tiled {
+                    let group = (vivante_y / 4) * 8 + vivante_x / 2;
+                    let in_super_tile = group * 8 + (vivante_y % 4) * 2 + vivante_x % 2;
+                    (tile_y * tiles_per_row + tile_x) * 256 + in_super_tile
+                } else {
+                    let vivante_y = tile_y * (tile_height / 4) + vivante_y;
+                    vivante_y * tiles_per_row + tile_x
+                };
+
+                let base = if split {
+                    let half = pitch * lines / 2;
+                    (vivante_tile % 2) * half + (vivante_tile / 2) * vivante_tile_size
+                } else {
+                    vivante_tile * vivante_tile_size
+                };
+                base + y * vivante_tile_width + x
+            }
+        }
+    }
+}
+
+/// Is this block linear layout one of the `Nvidia_16bx2_block_*_gob` modifiers, or their
+/// parameterized equivalent?
+fn is_16bx2(block_linear: &NvidiaBlockLinear) -> bool {
+    let legacy = NvidiaBlockLinear {
+        log2_block_height: block_linear.log2_block_height,
+        ..NvidiaBlockLinear::default()
+    };
+    block_linear.log2_block_height <= 5 && block_linear.is_equivalent(&legacy)
+}
+
+/// A tiled buffer and its dimensions, validated so that no offset computation can overflow.
+struct Surface {
+    planes: [Option<Plane>; 2],
+    linear_size: usize,
+    tiled_size: usize,
+}
+
+/// A single plane of a [`Surface`].
+struct Plane {
+    tiling: Tiling,
+    line_size: usize,
+    height: usize,
+    lines: usize,
+    pitch: usize,
+    linear_offset: usize,
+    tiled_offset: usize,
+}
+
+impl Surface {
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    fn new(
+        format: DrmFourcc,
+        modifier: DrmModifier,
+        uif: Option<UifLayout>,
+        width: u32,
+        height: u32,
+        pitch: u32,
+    ) -> Result<Self, TilingError> {
+        let mut surface = Surface {
+            planes: [None, None],
+            linear_size: 0,
+            tiled_size: 0,
+        };
+
+        let (_, info) = Tiling::new(format, modifier, 0)?;
+        for plane in 0..usize::from(info.num_planes) {
+            let (mut tiling, _) = Tiling::new(format, modifier, plane)?;
+            let geometry = tiling.geometry();
+
+            let line_size = info.min_pitch(plane, width);
+            if pitch % geometry.width != 0 || line_size > pitch.into() {
+                return Err(TilingError::InvalidPitch(pitch));
+            }
+
+            let height = info.plane_height(plane, height);
+            let mut lines =
+                u64::from(height.div_ceil(geometry.height)) * u64::from(geometry.height);
+            if let (Tiling::Uif { utile, .. }, Some(uif)) = (tiling, uif) {
+                let column = u64::from(uif.block_rows) * u64::from(geometry.height);
+                if column < lines || (uif.xor && uif.block_rows % 32 != 0) {
+                    return Err(TilingError::InvalidColumnHeight(uif.block_rows));
+                }
+                tiling = Tiling::Uif {
+                    utile,
+                    xor: uif.xor,
+                };
+                lines = column;
+            }
+            let tiled_size =
+                usize::try_from(lines * u64::from(pitch)).map_err(|_| TilingError::Overflow)?;
+            let linear_size = usize::try_from(u64::from(height) * u64::from(pitch))
+                .map_err(|_| TilingError::Overflow)?;
+
+            surface.planes[plane] = Some(Plane {
+                tiling,
+                line_size: line_size as usize,
+                height: height as usize,
+                lines: lines as usize,
+                pitch: pitch as usize,
+                linear_offset: surface.linear_size,
+                tiled_offset: surface.tiled_size,
+            });
+            surface.linear_size = (surface.linear_size)
+                .checked_add(linear_size)
+                .ok_or(TilingError::Overflow)?;
+            surface.tiled_size = (surface.tiled_size)
+                .checked_add(tiled_size)
+                .ok_or(TilingError::Overflow)?;
+        }
+
+        Ok(surface)
+    }
+
+    fn untile(&self, src: &[u8], dst: &mut [u8]) -> Result<(), TilingError> {
+        if src.len() < self.tiled_size || dst.len() < self.linear_size {
+            return Err(TilingError::BufferTooSmall);
+        }
+
+        self.for_each_span(|linear, tiled, len| {
+            dst[linear..linear + len].copy_from_slice(&src[tiled..tiled + len])
+        });
+        Ok(())
+    }
+
+    fn tile(&self, src: &[u8], dst: &mut [u8]) -> Result<(), TilingError> {
+        if src.len() < self.linear_size || dst.len() < self.tiled_size {
+            return Err(TilingError::BufferTooSmall);
+        }
+
+        self.for_each_span(|linear, tiled, len| {
+            dst[tiled..tiled + len].copy_from_slice(&src[linear..linear + len])
+        });
+        Ok(())
+    }
+
+    /// Call `f(linear_offset, tiled_offset, len)` for each run of bytes contiguous in both layouts.
+    fn for_each_span(&self, mut f: impl FnMut(usize, usize, usize)) {
+        for plane in self.planes.iter().flatten() {
+            let span = plane.tiling.span();
+
+            for y in 0..plane.height {
+                for x in (0..plane.line_size).step_by(span) {
+                    let tiled = plane.tiling.offset(x, y, plane.pitch, plane.lines);
+                    let len = span.min(plane.line_size - x);
+                    f(
+                        plane.linear_offset + y * plane.pitch + x,
+                        plane.tiled_offset + tiled,
+                        len,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn round_trip(format: DrmFourcc, modifier: DrmModifier, width: u32, height: u32) {
+        let info = format.info().unwrap();
+        let geometry = tile_geometry(format, modifier).unwrap();
+        let pitch = (info.min_pitch(0, width) as u32).div_ceil(geometry.width) * geometry.width;
+        let lines = height.div_ceil(geometry.height) * geometry.height;
+
+        // Generous enough for any padding of the chroma planes
+        let linear: Vec<u8> = (0..pitch * height * 2).map(|i| (i % 251) as u8).collect();
+        let mut tiled = vec![0; (pitch * lines * 2) as usize];
+        let mut back = vec![0; linear.len()];
+
+        tile(&linear, &mut tiled, format, modifier, width, height, pitch).unwrap();
+        untile(&tiled, &mut back, format, modifier, width, height, pitch).unwrap();
+
+        let mut offset = 0;
+        for plane in 0..info.num_planes as usize {
+            let line_size = info.min_pitch(plane, width) as usize;
+            for _ in 0..info.plane_height(plane, height) {
+                let line = offset..offset + line_size;
+                assert_eq!(back[line.clone()], linear[line]);
+                offset += pitch as usize;
+            }
+        }
+    }
+
+    /// Tile a buffer `pitch` bytes by `height` lines in which only the bytes at the given linear
+    /// offsets are set, and check that each of them, and nothing else, lands at its tiled offset.
+    fn assert_placement(
+        format: DrmFourcc,
+        modifier: DrmModifier,
+        (pitch, height): (u32, u32),
+        placement: &[(usize, usize)],
+    ) {
+        let width = pitch / u32::from(format.info().unwrap().char_per_block[0]);
+        let geometry = tile_geometry(format, modifier).unwrap();
+        let lines = height.div_ceil(geometry.height) * geometry.height;
+
+        // Generous enough for the chroma planes
+        let mut linear = vec![0; (pitch * height * 2) as usize];
+        for (i, &(offset, _)) in placement.iter().enumerate() {
+            linear[offset] = i as u8 + 1;
+        }
+        let mut tiled = vec![0; (pitch * lines * 2) as usize];
+        tile(&linear, &mut tiled, format, modifier, width, height, pitch).unwrap();
+
+        for (i, &(linear, offset)) in placement.iter().enumerate() {
+            assert_eq!(tiled[offset], i as u8 + 1, "linear offset {}", linear);
+        }
+        let set = tiled.iter().filter(|&&byte| byte != 0).count();
+        assert_eq!(set, placement.len());
+    }
+
+    #[test]
+    fn intel_round_trips() {
+        round_trip(DrmFourcc::Xrgb8888, DrmModifier::I915_x_tiled, 300, 21);
+        round_trip(DrmFourcc::Xrgb8888, DrmModifier::I915_y_tiled, 300, 45);
+        round_trip(DrmFourcc::Rgb888, DrmModifier::I915_y_tiled, 100, 3);
+    }
+
+    #[test]
+    fn intel_placement() {
+        // 512 bytes by 8 lines X tiles, stored line by line
+        let placement = [
+            (1024 + 1, 512 + 1),
+            (512, 4096),
+            (8 * 1024, 2 * 4096),
+            (9 * 1024 + 600, 3 * 4096 + 512 + 88),
+        ];
+        assert_placement(
+            DrmFourcc::R8,
+            DrmModifier::I915_x_tiled,
+            (1024, 16),
+            &placement,
+        );
+
+        // 128 bytes by 32 lines Y tiles, stored as columns of 16 bytes
+        let placement = [
+            (16, 512),
+            (1024, 16),
+            (2 * 1024 + 17, 512 + 2 * 16 + 1),
+            (128, 4096),
+            (32 * 1024, 8 * 4096),
+            (33 * 1024 + 130, 9 * 4096 + 16 + 2),
+        ];
+        assert_placement(
+            DrmFourcc::R8,
+            DrmModifier::I915_y_tiled,
+            (1024, 64),
+            &placement,
+        );
+    }
+
+    #[test]
+    fn broadcom_round_trips() {
+        for &format in &[DrmFourcc::R8, DrmFourcc::Rgb565, DrmFourcc::Xrgb8888] {
+            round_trip(format, DrmModifier::Broadcom_vc4_t_tiled, 100, 70);
+            round_trip(format, DrmModifier::Broadcom_uif, 100, 70);
+        }
+        round_trip(DrmFourcc::Abgr16161616f, DrmModifier::Broadcom_uif, 33, 9);
+    }
+
+    #[test]
+    fn vc4_t_placement() {
+        // With 32 bit pixels T tiles are 128 bytes by 32 lines, made of four 64 bytes by 16 lines
+        // subtiles of 16 bytes by 4 lines utiles.
+        let placement = [
+            // Utiles within a subtile, line by line
+            (4 * 256 + 16, 5 * 64),
+            (5 * 256 + 20, 5 * 64 + 16 + 4),
+            // Even rows of tiles run left to right, with subtiles in the order top left, bottom
+            // left, bottom right then top right.
+            (64, 3 * 1024),
+            (16 * 256, 1024),
+            (16 * 256 + 64, 2 * 1024),
+            (128, 4096),
+            // Odd rows run right to left, with subtiles in the order bottom right, top right, top
+            // left then bottom left.
+            (32 * 256, 3 * 4096 + 2 * 1024),
+            (32 * 256 + 64, 3 * 4096 + 1024),
+            (48 * 256, 3 * 4096 + 3 * 1024),
+            (48 * 256 + 64, 3 * 4096),
+            (32 * 256 + 128, 2 * 4096 + 2 * 1024),
+        ];
+        assert_placement(
+            DrmFourcc::Xrgb8888,
+            DrmModifier::Broadcom_vc4_t_tiled,
+            (256, 64),
+            &placement,
+        );
+    }
+
+    #[test]
+    fn uif_placement() {
+        // With 32 bit pixels UIF blocks are 32 bytes by 8 lines, made of 16 bytes by 4 lines
+        // utiles, and columns are 128 bytes wide and 4 blocks tall.
+        let placement = [
+            (16, 64),
+            (4 * 256, 128),
+            (5 * 256 + 20, 3 * 64 + 16 + 4),
+            (32, 256),
+            (8 * 256, 4 * 256),
+            (128, 16 * 256),
+            (8 * 256 + 160, 21 * 256),
+        ];
+        assert_placement(
+            DrmFourcc::Xrgb8888,
+            DrmModifier::Broadcom_uif,
+            (256, 32),
+            &placement,
+        );
+    }
+
+    /// `v3d_get_uif_pixel_offset` from Mesa, with the utiles of `v3d_utile_width` and
+    /// `v3d_utile_height`.
+    fn v3d_uif_offset(cpp: usize, padded_height: usize, x: usize, y: usize, do_xor: bool) -> usize {
+        let utile_w = match cpp {
+            1 | 2 => 8,
+            4 | 8 => 4,
+            _ => 2,
+        };
+        let utile_h = match cpp {
+            1 => 8,
+            2 | 4 => 4,
+            _ => 2,
+        };
+        let (mb_width, mb_height) = (utile_w * 2, utile_h * 2);
+
+        let mb_x = x / mb_width;
+        let mut mb_y = y / mb_height;
+        let (mb_pixel_x, mb_pixel_y) = (x % mb_width, y % mb_height);
+
+        if do_xor && (mb_x / 4) & 1 == 1 {
+            mb_y ^= 0x10;
+        }
+
+        let mb_h = padded_height.div_ceil(mb_height);
+        let mb_id = (mb_x / 4) * ((mb_h - 1) * 4) + mb_x + mb_y * 4;
+
+        let top = mb_pixel_y < utile_h;
+        let left = mb_pixel_x < utile_w;
+        let mb_tile_offset = usize::from(!top) * 128 + usize::from(!left) * 64;
+
+        let (utile_x, utile_y) = (mb_pixel_x % utile_w, mb_pixel_y % utile_h);
+        mb_id * 256 + mb_tile_offset + cpp * (utile_y * utile_w + utile_x)
+    }
+
+    #[test]
+    fn uif_offsets() {
+        // Every size V3D supports, including 16 bytes per pixel which no DRM format uses yet
+        for &cpp in &[1, 2, 4, 8, 16] {
+            let utile = Utile::v3d(cpp as u32).unwrap();
+            let (pitch, lines) = (512, 32 * utile.height * 2);
+
+            for &xor in &[false, true] {
+                let tiling = Tiling::Uif { utile, xor };
+                for y in 0..lines {
+                    for x in 0..pitch {
+                        let expected = v3d_uif_offset(cpp, lines, x / cpp, y, xor) + x % cpp;
+                        let offset = tiling.offset(x, y, pitch, lines);
+                        assert_eq!(offset, expected, "{} bytes per pixel", cpp);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn uif_layouts() {
+        let (width, height) = (64, 200);
+
+        for &format in &[DrmFourcc::Xrgb8888, DrmFourcc::Abgr16161616f] {
+            let cpp = format.info().unwrap().char_per_block[0] as u32;
+            let pitch = width * cpp;
+            let block_height = tile_geometry(format, DrmModifier::Broadcom_uif)
+                .unwrap()
+                .height;
+            // Each pixel starts with its index
+            let linear: Vec<u8> = (0..width * height)
+                .flat_map(|pixel: u32| {
+                    let mut bytes = vec![0xff; cpp as usize];
+                    bytes[..4].copy_from_slice(&pixel.to_le_bytes());
+                    bytes
+                })
+                .collect();
+
+            let rows = height.div_ceil(block_height);
+            for &(block_rows, xor) in &[(rows, false), (rows + 7, false), (64, true)] {
+                let layout = UifLayout { block_rows, xor };
+                let lines = (block_rows * block_height) as usize;
+                let mut tiled = vec![0; pitch as usize * lines];
+                tile_uif(&linear, &mut tiled, format, layout, width, height, pitch).unwrap();
+
+                for y in 0..height as usize {
+                    for x in 0..width as usize {
+                        let offset = v3d_uif_offset(cpp as usize, lines, x, y, xor);
+                        let bytes = <[u8; 4]>::try_from(&tiled[offset..offset + 4]).unwrap();
+                        let pixel = u32::from_le_bytes(bytes);
+                        assert_eq!(pixel as usize, y * width as usize + x, "{:?}", layout);
+                    }
+                }
+
+                let mut back = vec![0; linear.len()];
+                untile_uif(&tiled, &mut back, format, layout, width, height, pitch).unwrap();
+                assert_eq!(back, linear);
+            }
+        }
+
+        let tiled = vec![0; 256 * 256];
+        let mut linear = vec![0; 256 * 200];
+        for &(block_rows, xor) in &[(24, false), (48, true)] {
+            let layout = UifLayout { block_rows, xor };
+            assert_eq!(
+                untile_uif(
+                    &tiled,
+                    &mut linear,
+                    DrmFourcc::Xrgb8888,
+                    layout,
+                    64,
+                    200,
+                    256
+                ),
+                Err(TilingError::InvalidColumnHeight(block_rows))
+            );
+        }
+    }
+
+    #[test]
+    fn nvidia_round_trips() {
+        let modifiers = [
+            DrmModifier::Nvidia_16bx2_block_one_gob,
+            DrmModifier::Nvidia_16bx2_block_two_gob,
+            DrmModifier::Nvidia_16bx2_block_four_gob,
+            DrmModifier::Nvidia_16bx2_block_eight_gob,
+            DrmModifier::Nvidia_16bx2_block_sixteen_gob,
+            DrmModifier::Nvidia_16bx2_block_thirtytwo_gob,
+        ];
+        for (log2_block_height, &modifier) in modifiers.iter().enumerate() {
+            let geometry = tile_geometry(DrmFourcc::Xrgb8888, modifier).unwrap();
+            assert_eq!(geometry.height, 8 << log2_block_height);
+
+            round_trip(DrmFourcc::Xrgb8888, modifier, 100, 70);
+            round_trip(DrmFourcc::R8, modifier, 100, 300);
+        }
+    }
+
+    #[test]
+    fn nvidia_placement() {
+        // GOBs are 64 bytes by 8 lines, made of 16 bytes by 2 lines sectors. Two GOBs make a block.
+        let placement = [
+            (1, 1),
+            (16, 32),
+            (256, 16),
+            (32, 256),
+            (2 * 256, 64),
+            (3 * 256 + 17, 64 + 32 + 16 + 1),
+            (7 * 256 + 33, 256 + 3 * 64 + 16 + 1),
+            (8 * 256, 512),
+            (64, 1024),
+            (16 * 256, 4 * 1024),
+        ];
+        assert_placement(
+            DrmFourcc::R8,
+            DrmModifier::Nvidia_16bx2_block_two_gob,
+            (256, 32),
+            &placement,
+        );
+    }
+
+    #[test]
+    fn video_round_trips() {
+        let modifiers = [
+            DrmModifier::Samsung_64_32_tile,
+            DrmModifier::Samsung_16_16_tile,
+            DrmModifier::Generic_16_16_tile,
+            DrmModifier::Allwinner_tiled,
+        ];
+        for &modifier in &modifiers {
+            round_trip(DrmFourcc::Nv12, modifier, 300, 150);
+            round_trip(DrmFourcc::Nv21, modifier, 64, 96);
+        }
+    }
+
+    #[test]
+    fn video_placement() {
+        let chroma = 256 * 64;
+
+        // 64x32 tiles, two rows of four for luma, in Z order then mirrored Z order. The lone row
+        // of chroma tiles is stored left to right.
+        let placement = [
+            (64, 2048),
+            (128, 6 * 2048),
+            (192, 7 * 2048),
+            (32 * 256, 2 * 2048),
+            (32 * 256 + 128, 4 * 2048),
+            (33 * 256 + 200, 5 * 2048 + 64 + 8),
+            (chroma + 128, chroma + 2 * 2048),
+            (chroma + 256 + 200, chroma + 3 * 2048 + 64 + 8),
+        ];
+        assert_placement(
+            DrmFourcc::Nv12,
+            DrmModifier::Samsung_64_32_tile,
+            (256, 64),
+            &placement,
+        );
+
+        // 16x16 luma tiles and 16x8 chroma tiles, left to right
+        let placement = [
+            (16, 256),
+            (16 * 256, 16 * 256),
+            (5 * 256 + 35, 2 * 256 + 5 * 16 + 3),
+            (chroma + 16, chroma + 128),
+            (chroma + 8 * 256, chroma + 16 * 128),
+            (chroma + 5 * 256 + 35, chroma + 2 * 128 + 5 * 16 + 3),
+        ];
+        for &modifier in &[
+            DrmModifier::Samsung_16_16_tile,
+            DrmModifier::Generic_16_16_tile,
+        ] {
+            assert_placement(DrmFourcc::Nv12, modifier, (256, 64), &placement);
+        }
+
+        // 32x32 tiles for both planes
+        let placement = [
+            (32, 1024),
+            (32 * 256, 8 * 1024),
+            (33 * 256 + 40, 9 * 1024 + 32 + 8),
+            (chroma + 32, chroma + 1024),
+            (chroma + 31 * 256 + 5, chroma + 31 * 32 + 5),
+        ];
+        assert_placement(
+            DrmFourcc::Nv12,
+            DrmModifier::Allwinner_tiled,
+            (256, 64),
+            &placement,
+        );
+    }
+
+    #[test]
+    fn vivante_round_trips() {
+        let modifiers = [
+            DrmModifier::Vivante_tiled,
+            DrmModifier::Vivante_super_tiled,
+            DrmModifier::Vivante_split_tiled,
+            DrmModifier::Vivante_split_super_tiled,
+        ];
+        for &modifier in &modifiers {
+            round_trip(DrmFourcc::Xrgb8888, modifier, 100, 70);
+            round_trip(DrmFourcc::Rgb565, modifier, 33, 5);
+        }
+    }
+
+    #[test]
+    fn vivante_placement() {
+        // With 32 bit pixels tiles are 16 bytes by 4 lines, and super tiles 256 bytes by 64 lines.
+        let placement = [
+            (16, 64),
+            (4 * 256, 16 * 64),
+            (5 * 256 + 20, 17 * 64 + 16 + 4),
+        ];
+        assert_placement(
+            DrmFourcc::Xrgb8888,
+            DrmModifier::Vivante_tiled,
+            (256, 8),
+            &placement,
+        );
+
+        // Tiles within a 2x4 group, groups within a super tile, then super tiles
+        let placement = [
+            (16, 64),
+            (4 * 512, 2 * 64),
+            (32, 8 * 64),
+            (16 * 512, 64 * 64),
+            (21 * 512 + 100, 90 * 64 + 16 + 4),
+            (256, 16384),
+            (64 * 512, 2 * 16384),
+        ];
+        assert_placement(
+            DrmFourcc::Xrgb8888,
+            DrmModifier::Vivante_super_tiled,
+            (512, 128),
+            &placement,
+        );
+
+        // Odd tiles go to the second half of the buffer, here 32 lines of 256 bytes
+        let placement = [
+            (16, 4096),
+            (32, 64),
+            (4 * 256, 8 * 64),
+            (4 * 256 + 16, 4096 + 8 * 64),
+            (5 * 256 + 20, 4096 + 8 * 64 + 16 + 4),
+        ];
+        assert_placement(
+            DrmFourcc::Xrgb8888,
+            DrmModifier::Vivante_split_tiled,
+            (256, 32),
+            &placement,
+        );
+
+        // Likewise in super tile order, the buffer being 64 lines
+        let placement = [
+            (16, 8192),
+            (32, 4 * 64),
+            (4 * 256, 64),
+            (4 * 256 + 16, 8192 + 64),
+        ];
+        assert_placement(
+            DrmFourcc::Xrgb8888,
+            DrmModifier::Vivante_split_super_tiled,
+            (256, 64),
+            &placement,
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported() {
+        let mut dst = vec![0; 4096];
+        assert_eq!(
+            untile(
+                &[0; 4096],
+                &mut dst,
+                DrmFourcc::Xrgb8888,
+                DrmModifier::I915_x_tiled,
+                16,
+                8,
+                500
+            ),
+            Err(TilingError::InvalidPitch(500))
+        );
+        assert_eq!(
+            untile(
+                &[0; 4096],
+                &mut dst,
+                DrmFourcc::Nv12,
+                DrmModifier::I915_y_tiled,
+                16,
+                8,
+                128
+            ),
+            Err(TilingError::UnsupportedFormat(DrmFourcc::Nv12))
+        );
+        assert_eq!(
+            tile_geometry(DrmFourcc::Rgb888, DrmModifier::Broadcom_uif),
+            Err(TilingError::UnsupportedFormat(DrmFourcc::Rgb888))
+        );
+        assert_eq!(
+            tile_geometry(DrmFourcc::Xrgb8888, DrmModifier::Allwinner_tiled),
+            Err(TilingError::UnsupportedFormat(DrmFourcc::Xrgb8888))
+        );
+        let compressed = NvidiaBlockLinear {
+            compression: NvidiaBlockLinear::COMPRESSION_ROP_3D_1,
+            page_kind: NvidiaBlockLinear::PAGE_KIND_GENERIC,
+            ..NvidiaBlockLinear::default()
+        };
+        let modifier = DrmModifier::from(compressed.into_u64());
+        assert_eq!(
+            tile_geometry(DrmFourcc::Xrgb8888, modifier),
+            Err(TilingError::UnsupportedModifier(modifier))
+        );
+    }
+}
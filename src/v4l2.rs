@@ -0,0 +1,221 @@
+//! Mapping between V4L2 pixel formats (`V4L2_PIX_FMT_*` in `videodev2.h`) and [`DrmFormat`].
+//!
+//! V4L2 codes are fourccs too, but describe memory byte order rather than a little-endian word:
+//! V4L2 `RGB3` stores the bytes R, G, B, which is DRM's `BG24` ([`DrmFourcc::Bgr888`]).
+//!
+//! The multiplanar `M` variants, such as `NM12`, describe the same layout as their contiguous
+//! counterparts, but with each plane in its own buffer. Both map to the same [`DrmFormat`], as DRM
+//! describes every plane with its own handle and offset anyway.
+use crate::{DrmFormat, DrmFourcc, DrmModifier};
+
+const fn v4l2_fourcc(code: &[u8; 4]) -> u32 {
+    u32::from_le_bytes(*code)
+}
+
+const V4L2_PIX_FMT_RGB332: u32 = v4l2_fourcc(b"RGB1");
+const V4L2_PIX_FMT_RGB444: u32 = v4l2_fourcc(b"R444");
+const V4L2_PIX_FMT_ARGB444: u32 = v4l2_fourcc(b"AR12");
+const V4L2_PIX_FMT_XRGB444: u32 = v4l2_fourcc(b"XR12");
+const V4L2_PIX_FMT_RGB555: u32 = v4l2_fourcc(b"RGBO");
+const V4L2_PIX_FMT_ARGB555: u32 = v4l2_fourcc(b"AR15");
+const V4L2_PIX_FMT_XRGB555: u32 = v4l2_fourcc(b"XR15");
+const V4L2_PIX_FMT_RGB565: u32 = v4l2_fourcc(b"RGBP");
+const V4L2_PIX_FMT_BGR24: u32 = v4l2_fourcc(b"BGR3");
+const V4L2_PIX_FMT_RGB24: u32 = v4l2_fourcc(b"RGB3");
+const V4L2_PIX_FMT_BGR32: u32 = v4l2_fourcc(b"BGR4");
+const V4L2_PIX_FMT_ABGR32: u32 = v4l2_fourcc(b"AR24");
+const V4L2_PIX_FMT_XBGR32: u32 = v4l2_fourcc(b"XR24");
+const V4L2_PIX_FMT_BGRA32: u32 = v4l2_fourcc(b"RA24");
+const V4L2_PIX_FMT_BGRX32: u32 = v4l2_fourcc(b"RX24");
+const V4L2_PIX_FMT_RGBA32: u32 = v4l2_fourcc(b"AB24");
+const V4L2_PIX_FMT_RGBX32: u32 = v4l2_fourcc(b"XB24");
+const V4L2_PIX_FMT_ARGB32: u32 = v4l2_fourcc(b"BA24");
+const V4L2_PIX_FMT_XRGB32: u32 = v4l2_fourcc(b"BX24");
+const V4L2_PIX_FMT_GREY: u32 = v4l2_fourcc(b"GREY");
+const V4L2_PIX_FMT_Y16: u32 = v4l2_fourcc(b"Y16 ");
+const V4L2_PIX_FMT_YUYV: u32 = v4l2_fourcc(b"YUYV");
+const V4L2_PIX_FMT_YVYU: u32 = v4l2_fourcc(b"YVYU");
+const V4L2_PIX_FMT_UYVY: u32 = v4l2_fourcc(b"UYVY");
+const V4L2_PIX_FMT_VYUY: u32 = v4l2_fourcc(b"VYUY");
+const V4L2_PIX_FMT_NV12: u32 = v4l2_fourcc(b"NV12");
+const V4L2_PIX_FMT_NV21: u32 = v4l2_fourcc(b"NV21");
+const V4L2_PIX_FMT_NV16: u32 = v4l2_fourcc(b"NV16");
+const V4L2_PIX_FMT_NV61: u32 = v4l2_fourcc(b"NV61");
+const V4L2_PIX_FMT_NV24: u32 = v4l2_fourcc(b"NV24");
+const V4L2_PIX_FMT_NV42: u32 = v4l2_fourcc(b"NV42");
+const V4L2_PIX_FMT_P010: u32 = v4l2_fourcc(b"P010");
+const V4L2_PIX_FMT_NV12M: u32 = v4l2_fourcc(b"NM12");
+const V4L2_PIX_FMT_NV21M: u32 = v4l2_fourcc(b"NM21");
+const V4L2_PIX_FMT_NV16M: u32 = v4l2_fourcc(b"NM16");
+const V4L2_PIX_FMT_NV61M: u32 = v4l2_fourcc(b"NM61");
+const V4L2_PIX_FMT_YUV410: u32 = v4l2_fourcc(b"YUV9");
+const V4L2_PIX_FMT_YVU410: u32 = v4l2_fourcc(b"YVU9");
+const V4L2_PIX_FMT_YUV411P: u32 = v4l2_fourcc(b"411P");
+const V4L2_PIX_FMT_YUV420: u32 = v4l2_fourcc(b"YU12");
+const V4L2_PIX_FMT_YVU420: u32 = v4l2_fourcc(b"YV12");
+const V4L2_PIX_FMT_YUV422P: u32 = v4l2_fourcc(b"422P");
+const V4L2_PIX_FMT_YUV420M: u32 = v4l2_fourcc(b"YM12");
+const V4L2_PIX_FMT_YVU420M: u32 = v4l2_fourcc(b"YM21");
+const V4L2_PIX_FMT_YUV422M: u32 = v4l2_fourcc(b"YM16");
+const V4L2_PIX_FMT_YVU422M: u32 = v4l2_fourcc(b"YM61");
+const V4L2_PIX_FMT_YUV444M: u32 = v4l2_fourcc(b"YM24");
+const V4L2_PIX_FMT_YVU444M: u32 = v4l2_fourcc(b"YM42");
+const V4L2_PIX_FMT_NV12_COL128: u32 = v4l2_fourcc(b"NC12");
+const V4L2_PIX_FMT_NV12MT: u32 = v4l2_fourcc(b"TM12");
+const V4L2_PIX_FMT_NV12MT_16X16: u32 = v4l2_fourcc(b"VM12");
+const V4L2_PIX_FMT_SUNXI_TILED_NV12: u32 = v4l2_fourcc(b"ST12");
+
+/// Every supported V4L2 format, the DRM format it describes and whether it's multiplanar.
+///
+/// When several V4L2 formats describe the same DRM format, the first one is preferred.
+#[rustfmt::skip]
+const V4L2_FORMATS: &[(u32, DrmFourcc, DrmModifier, bool)] = &[
+    (V4L2_PIX_FMT_RGB332, DrmFourcc::Rgb332, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_ARGB444, DrmFourcc::Argb4444, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_XRGB444, DrmFourcc::Xrgb4444, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_RGB444, DrmFourcc::Xrgb4444, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_ARGB555, DrmFourcc::Argb1555, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_XRGB555, DrmFourcc::Xrgb1555, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_RGB555, DrmFourcc::Xrgb1555, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_RGB565, DrmFourcc::Rgb565, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_BGR24, DrmFourcc::Rgb888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_RGB24, DrmFourcc::Bgr888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_ABGR32, DrmFourcc::Argb8888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_XBGR32, DrmFourcc::Xrgb8888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_BGR32, DrmFourcc::Xrgb8888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_BGRA32, DrmFourcc::Rgba8888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_BGRX32, DrmFourcc::Rgbx8888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_RGBA32, DrmFourcc::Abgr8888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_RGBX32, DrmFourcc::Xbgr8888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_ARGB32, DrmFourcc::Bgra8888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_XRGB32, DrmFourcc::Bgrx8888, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_GREY, DrmFourcc::R8, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_Y16, DrmFourcc::R16, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_YUYV, DrmFourcc::Yuyv, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_YVYU, DrmFourcc::Yvyu, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_UYVY, DrmFourcc::Uyvy, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_VYUY, DrmFourcc::Vyuy, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_NV12, DrmFourcc::Nv12, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_NV21, DrmFourcc::Nv21, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_NV16, DrmFourcc::Nv16, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_NV61, DrmFourcc::Nv61, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_NV24, DrmFourcc::Nv24, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_NV42, DrmFourcc::Nv42, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_P010, DrmFourcc::P010, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_NV12M, DrmFourcc::Nv12, DrmModifier::Linear, true),
+    (V4L2_PIX_FMT_NV21M, DrmFourcc::Nv21, DrmModifier::Linear, true),
+    (V4L2_PIX_FMT_NV16M, DrmFourcc::Nv16, DrmModifier::Linear, true),
+    (V4L2_PIX_FMT_NV61M, DrmFourcc::Nv61, DrmModifier::Linear, true),
+    (V4L2_PIX_FMT_YUV410, DrmFourcc::Yuv410, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_YVU410, DrmFourcc::Yvu410, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_YUV411P, DrmFourcc::Yuv411, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_YUV420, DrmFourcc::Yuv420, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_YVU420, DrmFourcc::Yvu420, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_YUV422P, DrmFourcc::Yuv422, DrmModifier::Linear, false),
+    (V4L2_PIX_FMT_YUV420M, DrmFourcc::Yuv420, DrmModifier::Linear, true),
+    (V4L2_PIX_FMT_YVU420M, DrmFourcc::Yvu420, DrmModifier::Linear, true),
+    (V4L2_PIX_FMT_YUV422M, DrmFourcc::Yuv422, DrmModifier::Linear, true),
+    (V4L2_PIX_FMT_YVU422M, DrmFourcc::Yvu422, DrmModifier::Linear, true),
+    (V4L2_PIX_FMT_YUV444M, DrmFourcc::Yuv444, DrmModifier::Linear, true),
+    (V4L2_PIX_FMT_YVU444M, DrmFourcc::Yvu444, DrmModifier::Linear, true),
+    (V4L2_PIX_FMT_NV12_COL128, DrmFourcc::Nv12, DrmModifier::Broadcom_sand128, false),
+    (V4L2_PIX_FMT_NV12MT, DrmFourcc::Nv12, DrmModifier::Samsung_64_32_tile, true),
+    (V4L2_PIX_FMT_NV12MT_16X16, DrmFourcc::Nv12, DrmModifier::Samsung_16_16_tile, true),
+    (V4L2_PIX_FMT_SUNXI_TILED_NV12, DrmFourcc::Nv12, DrmModifier::Allwinner_tiled, false),
+];
+
+/// Whether a V4L2 format stores each plane in its own buffer
+///
+/// ```
+/// # use drm_fourcc::is_v4l2_multiplanar;
+/// assert!(is_v4l2_multiplanar(u32::from_le_bytes(*b"NM12")));
+/// assert!(!is_v4l2_multiplanar(u32::from_le_bytes(*b"NV12")));
+/// ```
+pub fn is_v4l2_multiplanar(pixelformat: u32) -> bool {
+    V4L2_FORMATS
+        .iter()
+        .any(|&(v4l2, _, _, multiplanar)| v4l2 == pixelformat && multiplanar)
+}
+
+impl DrmFormat {
+    /// The format described by a `V4L2_PIX_FMT_*` code.
+    ///
+    /// Tiled V4L2 formats map to the modifier describing their tiling.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFormat, DrmFourcc, DrmModifier};
+    /// # const V4L2_PIX_FMT_NV12_COL128: u32 = 0x3231_434e;
+    /// # const V4L2_PIX_FMT_RGB24: u32 = 0x3342_4752;
+    /// let format = DrmFormat::from_v4l2(V4L2_PIX_FMT_NV12_COL128).unwrap();
+    /// assert_eq!(format.code, DrmFourcc::Nv12);
+    /// assert_eq!(format.modifier, DrmModifier::Broadcom_sand128);
+    ///
+    /// assert_eq!(
+    ///     DrmFormat::from_v4l2(V4L2_PIX_FMT_RGB24).unwrap().code,
+    ///     DrmFourcc::Bgr888
+    /// );
+    /// ```
+    pub fn from_v4l2(pixelformat: u32) -> Option<DrmFormat> {
+        V4L2_FORMATS
+            .iter()
+            .find(|&&(v4l2, _, _, _)| v4l2 == pixelformat)
+            .map(|&(_, code, modifier, _)| DrmFormat { code, modifier })
+    }
+
+    /// The `V4L2_PIX_FMT_*` code describing this format.
+    ///
+    /// If `multiplanar` is set the `M` variant is returned when there is one, otherwise the
+    /// contiguous one is. Formats with an implicit modifier aren't mapped, as their layout is
+    /// unknown.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFormat, DrmFourcc, DrmModifier};
+    /// # const V4L2_PIX_FMT_NV12: u32 = 0x3231_564e;
+    /// # const V4L2_PIX_FMT_NV12M: u32 = 0x3231_4d4e;
+    /// let nv12 = DrmFormat {
+    ///     code: DrmFourcc::Nv12,
+    ///     modifier: DrmModifier::Linear,
+    /// };
+    /// assert_eq!(nv12.to_v4l2(false), Some(V4L2_PIX_FMT_NV12));
+    /// assert_eq!(nv12.to_v4l2(true), Some(V4L2_PIX_FMT_NV12M));
+    /// ```
+    pub fn to_v4l2(&self, multiplanar: bool) -> Option<u32> {
+        let mut matching = V4L2_FORMATS
+            .iter()
+            .filter(|&&(_, code, modifier, _)| code == self.code && modifier == self.modifier);
+
+        let first = matching.clone().next()?;
+        let preferred = matching
+            .find(|&&(_, _, _, is_multiplanar)| is_multiplanar == multiplanar)
+            .unwrap_or(first);
+
+        Some(preferred.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        for &(v4l2, code, modifier, multiplanar) in V4L2_FORMATS {
+            let format = DrmFormat::from_v4l2(v4l2).unwrap();
+            assert_eq!(format, DrmFormat { code, modifier });
+
+            let preferred = format.to_v4l2(multiplanar).unwrap();
+            assert_eq!(DrmFormat::from_v4l2(preferred), Some(format));
+            assert_eq!(is_v4l2_multiplanar(preferred), multiplanar);
+        }
+    }
+
+    #[test]
+    fn rejects_implicit_modifier() {
+        let format = DrmFormat {
+            code: DrmFourcc::Nv12,
+            modifier: DrmModifier::Invalid,
+        };
+        assert_eq!(format.to_v4l2(false), None);
+        assert_eq!(DrmFormat::from_v4l2(0), None);
+    }
+}
@@ -0,0 +1,218 @@
+//! Mapping between [`DrmFourcc`] and `VkFormat`, for importing dma-bufs into Vulkan.
+//!
+//! Formats are exchanged as raw `i32` values so any version of the Vulkan bindings can be used.
+use crate::DrmFourcc;
+
+const VK_FORMAT_R4G4B4A4_UNORM_PACK16: i32 = 2;
+const VK_FORMAT_B4G4R4A4_UNORM_PACK16: i32 = 3;
+const VK_FORMAT_R5G6B5_UNORM_PACK16: i32 = 4;
+const VK_FORMAT_B5G6R5_UNORM_PACK16: i32 = 5;
+const VK_FORMAT_R5G5B5A1_UNORM_PACK16: i32 = 6;
+const VK_FORMAT_B5G5R5A1_UNORM_PACK16: i32 = 7;
+const VK_FORMAT_A1R5G5B5_UNORM_PACK16: i32 = 8;
+const VK_FORMAT_R8_UNORM: i32 = 9;
+const VK_FORMAT_R8G8_UNORM: i32 = 16;
+const VK_FORMAT_R8G8B8_UNORM: i32 = 23;
+const VK_FORMAT_B8G8R8_UNORM: i32 = 30;
+const VK_FORMAT_R8G8B8A8_UNORM: i32 = 37;
+const VK_FORMAT_B8G8R8A8_UNORM: i32 = 44;
+const VK_FORMAT_A2R10G10B10_UNORM_PACK32: i32 = 58;
+const VK_FORMAT_A2B10G10R10_UNORM_PACK32: i32 = 64;
+const VK_FORMAT_R16_UNORM: i32 = 70;
+const VK_FORMAT_R16G16_UNORM: i32 = 77;
+const VK_FORMAT_R16G16B16A16_UNORM: i32 = 91;
+const VK_FORMAT_R16G16B16A16_SFLOAT: i32 = 97;
+const VK_FORMAT_G8B8G8R8_422_UNORM: i32 = 1000156000;
+const VK_FORMAT_B8G8R8G8_422_UNORM: i32 = 1000156001;
+const VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM: i32 = 1000156002;
+const VK_FORMAT_G8_B8R8_2PLANE_420_UNORM: i32 = 1000156003;
+const VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM: i32 = 1000156004;
+const VK_FORMAT_G8_B8R8_2PLANE_422_UNORM: i32 = 1000156005;
+const VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM: i32 = 1000156006;
+const VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16: i32 = 1000156010;
+const VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16: i32 = 1000156013;
+const VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16: i32 = 1000156015;
+const VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16: i32 = 1000156020;
+const VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16: i32 = 1000156023;
+const VK_FORMAT_G16B16G16R16_422_UNORM: i32 = 1000156027;
+const VK_FORMAT_G16_B16R16_2PLANE_420_UNORM: i32 = 1000156030;
+const VK_FORMAT_G8_B8R8_2PLANE_444_UNORM: i32 = 1000330000;
+
+/// A `VkComponentSwizzle`, with the same discriminants
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(i32)]
+pub enum VkComponentSwizzle {
+    Identity = 0,
+    Zero = 1,
+    One = 2,
+    R = 3,
+    G = 4,
+    B = 5,
+    A = 6,
+}
+
+/// A `VkComponentMapping`, to be used in the image view or YCbCr conversion of the image.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VkComponentMapping {
+    pub r: VkComponentSwizzle,
+    pub g: VkComponentSwizzle,
+    pub b: VkComponentSwizzle,
+    pub a: VkComponentSwizzle,
+}
+
+impl VkComponentMapping {
+    /// Every component is read as-is
+    pub const IDENTITY: Self = VkComponentMapping {
+        r: VkComponentSwizzle::Identity,
+        g: VkComponentSwizzle::Identity,
+        b: VkComponentSwizzle::Identity,
+        a: VkComponentSwizzle::Identity,
+    };
+
+    /// Whether no component is swizzled
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+}
+
+/// How to view a [`DrmFourcc`] in Vulkan
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VkFormatMapping {
+    /// The raw `VkFormat`
+    pub format: i32,
+    /// The swizzle to apply so components read as the fourcc describes them
+    pub components: VkComponentMapping,
+}
+
+const IDENTITY: VkComponentMapping = VkComponentMapping::IDENTITY;
+/// The padding of an X format is read as opaque alpha
+const OPAQUE: VkComponentMapping = VkComponentMapping {
+    a: VkComponentSwizzle::One,
+    ..IDENTITY
+};
+/// Chroma is stored Cr first
+const SWAP_RB: VkComponentMapping = VkComponentMapping {
+    r: VkComponentSwizzle::B,
+    b: VkComponentSwizzle::R,
+    ..IDENTITY
+};
+
+/// Every supported format. The first identity-swizzled entry of a `VkFormat` is what it maps back
+/// to.
+#[rustfmt::skip]
+const VK_FORMATS: &[(DrmFourcc, i32, VkComponentMapping)] = &[
+    (DrmFourcc::Argb8888, VK_FORMAT_B8G8R8A8_UNORM, IDENTITY),
+    (DrmFourcc::Xrgb8888, VK_FORMAT_B8G8R8A8_UNORM, OPAQUE),
+    (DrmFourcc::Abgr8888, VK_FORMAT_R8G8B8A8_UNORM, IDENTITY),
+    (DrmFourcc::Xbgr8888, VK_FORMAT_R8G8B8A8_UNORM, OPAQUE),
+    (DrmFourcc::Bgr888, VK_FORMAT_R8G8B8_UNORM, IDENTITY),
+    (DrmFourcc::Rgb888, VK_FORMAT_B8G8R8_UNORM, IDENTITY),
+    (DrmFourcc::Rgba4444, VK_FORMAT_R4G4B4A4_UNORM_PACK16, IDENTITY),
+    (DrmFourcc::Rgbx4444, VK_FORMAT_R4G4B4A4_UNORM_PACK16, OPAQUE),
+    (DrmFourcc::Bgra4444, VK_FORMAT_B4G4R4A4_UNORM_PACK16, IDENTITY),
+    (DrmFourcc::Bgrx4444, VK_FORMAT_B4G4R4A4_UNORM_PACK16, OPAQUE),
+    (DrmFourcc::Rgb565, VK_FORMAT_R5G6B5_UNORM_PACK16, IDENTITY),
+    (DrmFourcc::Bgr565, VK_FORMAT_B5G6R5_UNORM_PACK16, IDENTITY),
+    (DrmFourcc::Rgba5551, VK_FORMAT_R5G5B5A1_UNORM_PACK16, IDENTITY),
+    (DrmFourcc::Rgbx5551, VK_FORMAT_R5G5B5A1_UNORM_PACK16, OPAQUE),
+    (DrmFourcc::Bgra5551, VK_FORMAT_B5G5R5A1_UNORM_PACK16, IDENTITY),
+    (DrmFourcc::Bgrx5551, VK_FORMAT_B5G5R5A1_UNORM_PACK16, OPAQUE),
+    (DrmFourcc::Argb1555, VK_FORMAT_A1R5G5B5_UNORM_PACK16, IDENTITY),
+    (DrmFourcc::Xrgb1555, VK_FORMAT_A1R5G5B5_UNORM_PACK16, OPAQUE),
+    (DrmFourcc::Argb2101010, VK_FORMAT_A2R10G10B10_UNORM_PACK32, IDENTITY),
+    (DrmFourcc::Xrgb2101010, VK_FORMAT_A2R10G10B10_UNORM_PACK32, OPAQUE),
+    (DrmFourcc::Abgr2101010, VK_FORMAT_A2B10G10R10_UNORM_PACK32, IDENTITY),
+    (DrmFourcc::Xbgr2101010, VK_FORMAT_A2B10G10R10_UNORM_PACK32, OPAQUE),
+    (DrmFourcc::Abgr16161616, VK_FORMAT_R16G16B16A16_UNORM, IDENTITY),
+    (DrmFourcc::Xbgr16161616, VK_FORMAT_R16G16B16A16_UNORM, OPAQUE),
+    (DrmFourcc::Abgr16161616f, VK_FORMAT_R16G16B16A16_SFLOAT, IDENTITY),
+    (DrmFourcc::Xbgr16161616f, VK_FORMAT_R16G16B16A16_SFLOAT, OPAQUE),
+    (DrmFourcc::R8, VK_FORMAT_R8_UNORM, IDENTITY),
+    (DrmFourcc::Gr88, VK_FORMAT_R8G8_UNORM, IDENTITY),
+    (DrmFourcc::R16, VK_FORMAT_R16_UNORM, IDENTITY),
+    (DrmFourcc::Gr1616, VK_FORMAT_R16G16_UNORM, IDENTITY),
+    (DrmFourcc::Yuyv, VK_FORMAT_G8B8G8R8_422_UNORM, IDENTITY),
+    (DrmFourcc::Yvyu, VK_FORMAT_G8B8G8R8_422_UNORM, SWAP_RB),
+    (DrmFourcc::Uyvy, VK_FORMAT_B8G8R8G8_422_UNORM, IDENTITY),
+    (DrmFourcc::Vyuy, VK_FORMAT_B8G8R8G8_422_UNORM, SWAP_RB),
+    (DrmFourcc::Y210, VK_FORMAT_G10X6B10X6G10X6R10X6_422_UNORM_4PACK16, IDENTITY),
+    (DrmFourcc::Y212, VK_FORMAT_G12X4B12X4G12X4R12X4_422_UNORM_4PACK16, IDENTITY),
+    (DrmFourcc::Y216, VK_FORMAT_G16B16G16R16_422_UNORM, IDENTITY),
+    (DrmFourcc::Nv12, VK_FORMAT_G8_B8R8_2PLANE_420_UNORM, IDENTITY),
+    (DrmFourcc::Nv21, VK_FORMAT_G8_B8R8_2PLANE_420_UNORM, SWAP_RB),
+    (DrmFourcc::Nv16, VK_FORMAT_G8_B8R8_2PLANE_422_UNORM, IDENTITY),
+    (DrmFourcc::Nv61, VK_FORMAT_G8_B8R8_2PLANE_422_UNORM, SWAP_RB),
+    (DrmFourcc::Nv24, VK_FORMAT_G8_B8R8_2PLANE_444_UNORM, IDENTITY),
+    (DrmFourcc::Nv42, VK_FORMAT_G8_B8R8_2PLANE_444_UNORM, SWAP_RB),
+    (DrmFourcc::Yuv420, VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM, IDENTITY),
+    (DrmFourcc::Yvu420, VK_FORMAT_G8_B8_R8_3PLANE_420_UNORM, SWAP_RB),
+    (DrmFourcc::Yuv422, VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM, IDENTITY),
+    (DrmFourcc::Yvu422, VK_FORMAT_G8_B8_R8_3PLANE_422_UNORM, SWAP_RB),
+    (DrmFourcc::Yuv444, VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM, IDENTITY),
+    (DrmFourcc::Yvu444, VK_FORMAT_G8_B8_R8_3PLANE_444_UNORM, SWAP_RB),
+    (DrmFourcc::P010, VK_FORMAT_G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16, IDENTITY),
+    (DrmFourcc::P210, VK_FORMAT_G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16, IDENTITY),
+    (DrmFourcc::P012, VK_FORMAT_G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16, IDENTITY),
+    (DrmFourcc::P016, VK_FORMAT_G16_B16R16_2PLANE_420_UNORM, IDENTITY),
+];
+
+impl DrmFourcc {
+    /// The `VkFormat` to import this format as, and the swizzle to view it with.
+    ///
+    /// X formats use the format of their alpha counterpart with alpha swizzled to one, and
+    /// formats storing Cr before Cb swap the red and blue components. Multi-planar formats need a
+    /// sampler YCbCr conversion to be sampled.
+    ///
+    /// ```
+    /// # use drm_fourcc::{DrmFourcc, VkComponentSwizzle};
+    /// let nv12 = DrmFourcc::Nv12.to_vk_format().unwrap();
+    /// assert_eq!(nv12.format, 1000156003); // VK_FORMAT_G8_B8R8_2PLANE_420_UNORM
+    /// assert!(nv12.components.is_identity());
+    ///
+    /// let xrgb = DrmFourcc::Xrgb8888.to_vk_format().unwrap();
+    /// assert_eq!(xrgb.format, 44); // VK_FORMAT_B8G8R8A8_UNORM
+    /// assert_eq!(xrgb.components.a, VkComponentSwizzle::One);
+    /// ```
+    pub fn to_vk_format(&self) -> Option<VkFormatMapping> {
+        VK_FORMATS
+            .iter()
+            .find(|(code, _, _)| code == self)
+            .map(|&(_, format, components)| VkFormatMapping { format, components })
+    }
+
+    /// The format a `VkFormat` can be imported from without swizzling.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFourcc;
+    /// assert_eq!(DrmFourcc::from_vk_format(44), Some(DrmFourcc::Argb8888));
+    /// assert_eq!(DrmFourcc::from_vk_format(0), None);
+    /// ```
+    pub fn from_vk_format(format: i32) -> Option<DrmFourcc> {
+        VK_FORMATS
+            .iter()
+            .find(|(_, vk_format, components)| *vk_format == format && components.is_identity())
+            .map(|&(code, _, _)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity_mappings_round_trip() {
+        for &(code, format, components) in VK_FORMATS {
+            let mapping = code.to_vk_format().unwrap();
+            assert_eq!(mapping, VkFormatMapping { format, components });
+
+            if components.is_identity() {
+                assert_eq!(DrmFourcc::from_vk_format(format), Some(code));
+            } else {
+                assert_ne!(DrmFourcc::from_vk_format(format), Some(code));
+            }
+        }
+    }
+}